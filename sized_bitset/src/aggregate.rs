@@ -0,0 +1,163 @@
+use crate::SizedBitset;
+
+/// Bit-sliced counters of `N` columns: `planes[j]` holds the bit `j` of every column count.
+///
+/// Adding a bitset ripples a carry through the planes, so it costs `O(log count)` bitset operations.
+/// Every operation is done in place to avoid allocating per input.
+#[derive(Debug, Clone)]
+struct VerticalCounter<const N: usize> {
+    planes: Vec<SizedBitset<N>>,
+    count: usize,
+}
+
+impl<const N: usize> VerticalCounter<N> {
+    fn new<'a>(bitsets: impl IntoIterator<Item = &'a SizedBitset<N>>) -> Self {
+        let mut counter = Self {
+            planes: Vec::new(),
+            count: 0,
+        };
+        for bitset in bitsets {
+            counter.add(bitset);
+        }
+        counter
+    }
+
+    fn add(&mut self, bitset: &SizedBitset<N>) {
+        let mut carry = *bitset;
+        for plane in self.planes.iter_mut() {
+            if carry.none() {
+                break;
+            }
+            let previous = *plane;
+            *plane ^= carry;
+            carry &= previous;
+        }
+        if carry.any() {
+            self.planes.push(carry);
+        }
+        self.count += 1;
+    }
+
+    /// Returns the columns whose counts are at least `k`, comparing from the highest plane.
+    fn at_least(&self, k: usize) -> SizedBitset<N> {
+        let width = self
+            .planes
+            .len()
+            .max((usize::BITS - k.leading_zeros()) as usize);
+        let zero = SizedBitset::from_const([false; N]);
+        let (mut greater, mut equal) = (zero, SizedBitset::from_const([true; N]));
+        for j in (0..width).rev() {
+            let plane = self.planes.get(j).unwrap_or(&zero);
+            if (k >> j) & 1 == 1 {
+                equal &= *plane;
+            } else {
+                let mut gain = equal;
+                gain &= *plane;
+                greater |= gain;
+                equal &= plane.flipped();
+            }
+        }
+        greater |= equal;
+        greater
+    }
+
+    fn counts(&self) -> [u32; N] {
+        std::array::from_fn(|i| {
+            self.planes
+                .iter()
+                .enumerate()
+                .map(|(j, plane)| (plane[i] as u32) << j)
+                .sum()
+        })
+    }
+}
+
+/// Aggregation
+impl<const N: usize> SizedBitset<N> {
+    /// Returns the bits set to true in any of `bitsets`.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::SizedBitset;
+    /// let bitsets = [
+    ///     SizedBitset::from_const([true, false, false]),
+    ///     SizedBitset::from_const([false, false, true]),
+    /// ];
+    /// assert_eq!(SizedBitset::union_all(&bitsets), SizedBitset::from_const([true, false, true]));
+    /// ```
+    pub fn union_all<'a>(bitsets: impl IntoIterator<Item = &'a Self>) -> Self {
+        let mut union = Self::from_const([false; N]);
+        for bitset in bitsets {
+            union |= *bitset;
+        }
+        union
+    }
+
+    /// Returns the bits set to true in all of `bitsets`, or all bits if `bitsets` is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::SizedBitset;
+    /// let bitsets = [
+    ///     SizedBitset::from_const([true, true, false]),
+    ///     SizedBitset::from_const([false, true, true]),
+    /// ];
+    /// assert_eq!(SizedBitset::intersection_all(&bitsets), SizedBitset::from_const([false, true, false]));
+    /// ```
+    pub fn intersection_all<'a>(bitsets: impl IntoIterator<Item = &'a Self>) -> Self {
+        let mut intersection = Self::from_const([true; N]);
+        for bitset in bitsets {
+            intersection &= *bitset;
+        }
+        intersection
+    }
+
+    /// Returns the bits set to true in more than half of `bitsets` (bitwise majority vote).
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::SizedBitset;
+    /// let bitsets = [
+    ///     SizedBitset::from_const([true, true, false]),
+    ///     SizedBitset::from_const([false, true, false]),
+    ///     SizedBitset::from_const([true, false, false]),
+    /// ];
+    /// assert_eq!(SizedBitset::majority(&bitsets), SizedBitset::from_const([true, true, false]));
+    /// ```
+    pub fn majority<'a>(bitsets: impl IntoIterator<Item = &'a Self>) -> Self {
+        let counter = VerticalCounter::new(bitsets);
+        counter.at_least(counter.count / 2 + 1)
+    }
+
+    /// Returns the bits set to true in at least `k` of `bitsets`.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::SizedBitset;
+    /// let bitsets = [
+    ///     SizedBitset::from_const([true, true, false]),
+    ///     SizedBitset::from_const([false, true, false]),
+    ///     SizedBitset::from_const([true, true, true]),
+    /// ];
+    /// assert_eq!(SizedBitset::threshold(&bitsets, 3), SizedBitset::from_const([false, true, false]));
+    /// assert_eq!(SizedBitset::threshold(&bitsets, 0), SizedBitset::from_const([true, true, true]));
+    /// ```
+    pub fn threshold<'a>(bitsets: impl IntoIterator<Item = &'a Self>, k: usize) -> Self {
+        VerticalCounter::new(bitsets).at_least(k)
+    }
+
+    /// Returns how many of `bitsets` have each bit set to true.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::SizedBitset;
+    /// let bitsets = [
+    ///     SizedBitset::from_const([true, true, false]),
+    ///     SizedBitset::from_const([false, true, false]),
+    /// ];
+    /// assert_eq!(SizedBitset::column_counts(&bitsets), [1, 2, 0]);
+    /// ```
+    pub fn column_counts<'a>(bitsets: impl IntoIterator<Item = &'a Self>) -> [u32; N] {
+        VerticalCounter::new(bitsets).counts()
+    }
+}
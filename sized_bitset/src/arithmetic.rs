@@ -0,0 +1,320 @@
+use crate::SizedBitset;
+
+/// Unsigned integer arithmetic
+///
+/// The bitset is treated as an `N`-bit unsigned integer whose bit `i` weighs `2^i`.
+impl<const N: usize> SizedBitset<N> {
+    /// Calculates `self + rhs` and returns a tuple of the wrapped sum along with the carry out of the top.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::convert::To8;
+    /// use sized_bitset::SizedBitset;
+    /// let (sum, carry) = SizedBitset::<4>::from_const([true, true, false, true])
+    ///     .overflowing_add(&SizedBitset::from_const([true, false, true, false]));
+    /// assert_eq!(sum.to_u8(), 0b0000);
+    /// assert!(carry);
+    /// ```
+    pub fn overflowing_add(&self, rhs: &Self) -> (Self, bool) {
+        let mut sum = Self::new();
+        let mut carry = false;
+        for i in 0..N {
+            sum[i] = self[i] ^ rhs[i] ^ carry;
+            carry = (self[i] & rhs[i]) | (carry & (self[i] ^ rhs[i]));
+        }
+        (sum, carry)
+    }
+
+    /// Calculates `self - rhs` and returns a tuple of the wrapped difference along with the borrow out of the top.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::convert::To8;
+    /// use sized_bitset::SizedBitset;
+    /// let (difference, borrow) = SizedBitset::<4>::from_const([true, false, false, false])
+    ///     .overflowing_sub(&SizedBitset::from_const([false, true, false, false]));
+    /// assert_eq!(difference.to_u8(), 0b1111);
+    /// assert!(borrow);
+    /// ```
+    pub fn overflowing_sub(&self, rhs: &Self) -> (Self, bool) {
+        let mut difference = Self::new();
+        let mut borrow = false;
+        for i in 0..N {
+            difference[i] = self[i] ^ rhs[i] ^ borrow;
+            borrow = (!self[i] & rhs[i]) | (borrow & !(self[i] ^ rhs[i]));
+        }
+        (difference, borrow)
+    }
+
+    /// Calculates `self * rhs` and returns a tuple of the wrapped product along with whether it overflowed.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::convert::To8;
+    /// use sized_bitset::SizedBitset;
+    /// let (product, overflow) = SizedBitset::<4>::from_const([true, true, false, false])
+    ///     .overflowing_mul(&SizedBitset::from_const([true, false, true, false]));
+    /// assert_eq!(product.to_u8(), 0b1111);
+    /// assert!(!overflow);
+    /// ```
+    pub fn overflowing_mul(&self, rhs: &Self) -> (Self, bool) {
+        (0..N)
+            .filter(|&j| rhs[j])
+            .fold((Self::new(), false), |(product, overflow), j| {
+                let (partial, high) = self.overflowing_shl(j);
+                let (product, carry) = product.overflowing_add(&partial);
+                (product, overflow | carry | high.any())
+            })
+    }
+
+    /// Calculates `-self` (= `0 - self`) and returns a tuple of the wrapped negation
+    /// along with whether it overflowed, which is whenever `self` is not zero.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::convert::To8;
+    /// use sized_bitset::SizedBitset;
+    /// let (negation, overflow) = SizedBitset::<4>::from_const([true, false, false, false]).overflowing_neg();
+    /// assert_eq!(negation.to_u8(), 0b1111);
+    /// assert!(overflow);
+    /// ```
+    pub fn overflowing_neg(&self) -> (Self, bool) {
+        Self::new().overflowing_sub(self)
+    }
+
+    /// Returns `self + rhs`, or `None` if it overflows.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::SizedBitset;
+    /// let x: SizedBitset<8> = 200u8.into();
+    /// assert_eq!(x.checked_add(&50u8.into()), Some(250u8.into()));
+    /// assert_eq!(x.checked_add(&60u8.into()), None);
+    /// ```
+    pub fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        let (sum, carry) = self.overflowing_add(rhs);
+        (!carry).then_some(sum)
+    }
+
+    /// Returns `self - rhs`, or `None` if it underflows.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::SizedBitset;
+    /// let x: SizedBitset<8> = 50u8.into();
+    /// assert_eq!(x.checked_sub(&20u8.into()), Some(30u8.into()));
+    /// assert_eq!(x.checked_sub(&60u8.into()), None);
+    /// ```
+    pub fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        let (difference, borrow) = self.overflowing_sub(rhs);
+        (!borrow).then_some(difference)
+    }
+
+    /// Returns `self * rhs`, or `None` if it overflows.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::SizedBitset;
+    /// let x: SizedBitset<8> = 50u8.into();
+    /// assert_eq!(x.checked_mul(&5u8.into()), Some(250u8.into()));
+    /// assert_eq!(x.checked_mul(&6u8.into()), None);
+    /// ```
+    pub fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+        let (product, overflow) = self.overflowing_mul(rhs);
+        (!overflow).then_some(product)
+    }
+
+    /// Returns `self / rhs`, or `None` if `rhs` is zero.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::SizedBitset;
+    /// let x: SizedBitset<8> = 50u8.into();
+    /// assert_eq!(x.checked_div(&7u8.into()), Some(7u8.into()));
+    /// assert_eq!(x.checked_div(&SizedBitset::new()), None);
+    /// ```
+    pub fn checked_div(&self, rhs: &Self) -> Option<Self> {
+        rhs.any().then(|| self.div_rem(rhs).0)
+    }
+
+    /// Returns `self % rhs`, or `None` if `rhs` is zero.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::SizedBitset;
+    /// let x: SizedBitset<8> = 50u8.into();
+    /// assert_eq!(x.checked_rem(&7u8.into()), Some(1u8.into()));
+    /// assert_eq!(x.checked_rem(&SizedBitset::new()), None);
+    /// ```
+    pub fn checked_rem(&self, rhs: &Self) -> Option<Self> {
+        rhs.any().then(|| self.div_rem(rhs).1)
+    }
+
+    /// Returns `-self`, or `None` unless `self` is zero.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::SizedBitset;
+    /// assert_eq!(SizedBitset::<8>::new().checked_neg(), Some(SizedBitset::new()));
+    /// assert_eq!(SizedBitset::<8>::from(1u8).checked_neg(), None);
+    /// ```
+    pub fn checked_neg(&self) -> Option<Self> {
+        let (negation, overflow) = self.overflowing_neg();
+        (!overflow).then_some(negation)
+    }
+
+    /// Returns `self + rhs`, saturating at the maximum (all bits set to true).
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::SizedBitset;
+    /// let x: SizedBitset<8> = 200u8.into();
+    /// assert_eq!(x.saturating_add(&60u8.into()), u8::MAX.into());
+    /// ```
+    pub fn saturating_add(&self, rhs: &Self) -> Self {
+        self.checked_add(rhs)
+            .unwrap_or_else(|| Self::new().flipped())
+    }
+
+    /// Returns `self - rhs`, saturating at zero.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::SizedBitset;
+    /// let x: SizedBitset<8> = 50u8.into();
+    /// assert_eq!(x.saturating_sub(&60u8.into()), SizedBitset::new());
+    /// ```
+    pub fn saturating_sub(&self, rhs: &Self) -> Self {
+        self.checked_sub(rhs).unwrap_or_default()
+    }
+
+    /// Returns `self * rhs`, saturating at the maximum (all bits set to true).
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::SizedBitset;
+    /// let x: SizedBitset<8> = 50u8.into();
+    /// assert_eq!(x.saturating_mul(&6u8.into()), u8::MAX.into());
+    /// ```
+    pub fn saturating_mul(&self, rhs: &Self) -> Self {
+        self.checked_mul(rhs)
+            .unwrap_or_else(|| Self::new().flipped())
+    }
+
+    /// Restoring long division, from the highest bit.
+    ///
+    /// Panics if `divisor` is zero.
+    fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        assert!(divisor.any(), "attempt to divide by zero");
+
+        let mut quotient = Self::new();
+        let mut remainder = Self::new();
+        for i in (0..N).rev() {
+            // the remainder is less than `divisor` before the shift, so one subtraction suffices.
+            let out = remainder.shl_in(self[i]);
+            let (difference, borrow) = remainder.overflowing_sub(divisor);
+            if out || !borrow {
+                remainder = difference;
+                quotient.set(i);
+            }
+        }
+        (quotient, remainder)
+    }
+}
+
+impl<const N: usize> core::ops::Add for SizedBitset<N> {
+    type Output = Self;
+
+    /// Returns the wrapping sum of `self` and `rhs`.
+    fn add(self, rhs: Self) -> Self::Output {
+        self.overflowing_add(&rhs).0
+    }
+}
+
+impl<const N: usize> core::ops::Sub for SizedBitset<N> {
+    type Output = Self;
+
+    /// Returns the wrapping difference of `self` and `rhs`.
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.overflowing_sub(&rhs).0
+    }
+}
+
+impl<const N: usize> core::ops::Mul for SizedBitset<N> {
+    type Output = Self;
+
+    /// Returns the wrapping product of `self` and `rhs`.
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.overflowing_mul(&rhs).0
+    }
+}
+
+impl<const N: usize> core::ops::Div for SizedBitset<N> {
+    type Output = Self;
+
+    /// Returns the quotient of `self` and `rhs`.
+    ///
+    /// Panics if `rhs` is zero.
+    fn div(self, rhs: Self) -> Self::Output {
+        self.div_rem(&rhs).0
+    }
+}
+
+impl<const N: usize> core::ops::Rem for SizedBitset<N> {
+    type Output = Self;
+
+    /// Returns the remainder of `self` and `rhs`.
+    ///
+    /// Panics if `rhs` is zero.
+    fn rem(self, rhs: Self) -> Self::Output {
+        self.div_rem(&rhs).1
+    }
+}
+
+impl<const N: usize> core::ops::Neg for SizedBitset<N> {
+    type Output = Self;
+
+    /// Returns the wrapping negation (two's complement) of `self`.
+    fn neg(self) -> Self::Output {
+        self.overflowing_neg().0
+    }
+}
+
+impl<const N: usize> core::ops::AddAssign for SizedBitset<N> {
+    /// Sets `self` to the wrapping sum of `self` and `other`.
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<const N: usize> core::ops::SubAssign for SizedBitset<N> {
+    /// Sets `self` to the wrapping difference of `self` and `other`.
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<const N: usize> core::ops::MulAssign for SizedBitset<N> {
+    /// Sets `self` to the wrapping product of `self` and `other`.
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl<const N: usize> core::ops::DivAssign for SizedBitset<N> {
+    /// Sets `self` to the quotient of `self` and `other`.
+    ///
+    /// Panics if `other` is zero.
+    fn div_assign(&mut self, other: Self) {
+        *self = *self / other;
+    }
+}
+
+impl<const N: usize> core::ops::RemAssign for SizedBitset<N> {
+    /// Sets `self` to the remainder of `self` and `other`.
+    ///
+    /// Panics if `other` is zero.
+    fn rem_assign(&mut self, other: Self) {
+        *self = *self % other;
+    }
+}
@@ -1,7 +1,12 @@
+use std::ops::RangeBounds;
+
 use deriving_via::DerivingVia;
 use itertools::Itertools;
 
-use crate::error::ConversionError;
+use crate::{
+    error::{ConversionError, FieldError},
+    GrayCodes, Lanes,
+};
 
 /// Statically-sized Bitset
 #[derive(Debug, Copy, Clone, DerivingVia)]
@@ -17,289 +22,1682 @@ impl<const N: usize> SizedBitset<N> {
     #[allow(unused)]
     const SIZE: usize = N;
 
-    /// Constructs [SizedBitset] from array of bool.
-    ///
-    /// It's for constant initialization.
+    /// Constructs [SizedBitset] from array of bool.
+    ///
+    /// It's for constant initialization.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::SizedBitset;
+    /// const BITSET: SizedBitset<4> = SizedBitset::from_const([true, true, false, false]);
+    /// ```
+    pub const fn from_const(bits: [bool; N]) -> Self {
+        Self { bits }
+    }
+
+    /// Returns [SizedBitset] that the bits in `start..end` are true and the others are false.
+    ///
+    /// Panics if `start > end` or `end > N`.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::SizedBitset;
+    /// const MASK: SizedBitset<4> = SizedBitset::mask_range(1, 3);
+    /// assert_eq!(MASK, SizedBitset::from_const([false, true, true, false]));
+    /// ```
+    pub const fn mask_range(start: usize, end: usize) -> Self {
+        assert!(start <= end && end <= N, "range out of bounds");
+
+        let mut bits = [false; N];
+        let mut i = start;
+        while i < end {
+            bits[i] = true;
+            i += 1;
+        }
+        Self { bits }
+    }
+
+    /// Returns [SizedBitset] that the lowest `k` bits are true and the others are false.
+    ///
+    /// Panics if `k > N`.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::SizedBitset;
+    /// const MASK: SizedBitset<4> = SizedBitset::mask_low(3);
+    /// assert_eq!(MASK, SizedBitset::from_const([true, true, true, false]));
+    /// ```
+    pub const fn mask_low(k: usize) -> Self {
+        Self::mask_range(0, k)
+    }
+
+    /// Returns [SizedBitset] that the highest `k` bits are true and the others are false.
+    ///
+    /// Panics if `k > N`.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::SizedBitset;
+    /// const MASK: SizedBitset<4> = SizedBitset::mask_high(1);
+    /// assert_eq!(MASK, SizedBitset::from_const([false, false, false, true]));
+    /// ```
+    pub const fn mask_high(k: usize) -> Self {
+        assert!(k <= N, "the number of bits exceeds the size");
+
+        Self::mask_range(N - k, N)
+    }
+
+    /// Returns [SizedBitset] that only the bit for the specified index is true.
+    ///
+    /// Panics if `i >= N`.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::SizedBitset;
+    /// const BIT: SizedBitset<4> = SizedBitset::single(2);
+    /// assert_eq!(BIT, SizedBitset::from_const([false, false, true, false]));
+    /// ```
+    pub const fn single(i: usize) -> Self {
+        Self::mask_range(i, i + 1)
+    }
+
+    /// Returns [SizedBitset] that the bits for even indices are true and the others are false (`..0101`).
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::SizedBitset;
+    /// const MASK: SizedBitset<4> = SizedBitset::alternating();
+    /// assert_eq!(MASK, SizedBitset::from_const([true, false, true, false]));
+    /// ```
+    pub const fn alternating() -> Self {
+        let mut bits = [false; N];
+        let mut i = 0;
+        while i < N {
+            bits[i] = true;
+            i += 2;
+        }
+        Self { bits }
+    }
+}
+
+impl<T: Into<bool> + Copy, const N: usize> From<[T; N]> for SizedBitset<N> {
+    /// Make [SizedBitset] from convertible array.
+    fn from(from: [T; N]) -> Self {
+        Self {
+            bits: from.map(|bit| bit.into()),
+        }
+    }
+}
+
+impl<T: Into<bool> + Copy, const N: usize> TryFrom<&[T]> for SizedBitset<N> {
+    type Error = core::array::TryFromSliceError;
+
+    /// Try make [SizedBitset] from convertible slice.
+    fn try_from(from: &[T]) -> Result<Self, Self::Error> {
+        let from: &[T; N] = from.try_into()?;
+        Ok(Self::from(*from))
+    }
+}
+
+impl<const N: usize> Default for SizedBitset<N> {
+    /// Returns [SizedBitset] that all bits is false
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> SizedBitset<N> {
+    /// Returns [SizedBitset] that all bits is false
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::{convert::*, SizedBitset};
+    /// let bitset = SizedBitset::<8>::new();
+    ///
+    /// assert_eq!(bitset.to_u8(), 0b00000000);
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            bits: unsafe {
+                (0..N)
+                    .map(|_| false)
+                    .collect_vec()
+                    .as_slice()
+                    .try_into()
+                    .unwrap_unchecked()
+            },
+        }
+    }
+
+    /// Constructs [SizedBitset] whose `i`-th bit is `f(i)`.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::{convert::*, SizedBitset};
+    /// let bitset = SizedBitset::<8>::from_fn(|i| i % 3 == 0);
+    ///
+    /// assert_eq!(bitset.to_u8(), 0b01001001);
+    /// ```
+    pub fn from_fn(f: impl FnMut(usize) -> bool) -> Self {
+        Self {
+            bits: core::array::from_fn(f),
+        }
+    }
+
+    /// Try make [SizedBitset] from an iterator of exactly `N` bits, from the lowest bit.
+    ///
+    /// Returns an error if the iterator yields fewer or more than `N` bits.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::{error::ConversionError, SizedBitset};
+    /// let bitset = SizedBitset::<4>::try_from_iter([true, false, true, true]).unwrap();
+    /// assert_eq!(bitset, SizedBitset::from_const([true, false, true, true]));
+    ///
+    /// assert!(matches!(
+    ///     SizedBitset::<4>::try_from_iter([true, false]),
+    ///     Err(ConversionError::TooShort { expected: 4, actual: 2 })
+    /// ));
+    /// assert!(matches!(
+    ///     SizedBitset::<4>::try_from_iter([true; 5]),
+    ///     Err(ConversionError::TooLong { expected: 4 })
+    /// ));
+    /// ```
+    pub fn try_from_iter(iter: impl IntoIterator<Item = bool>) -> Result<Self, ConversionError> {
+        let mut iter = iter.into_iter();
+        let mut bits = [false; N];
+
+        for (actual, bit) in bits.iter_mut().enumerate() {
+            *bit = iter.next().ok_or(ConversionError::TooShort {
+                expected: N,
+                actual,
+            })?;
+        }
+
+        match iter.next() {
+            Some(_) => Err(ConversionError::TooLong { expected: N }),
+            None => Ok(Self { bits }),
+        }
+    }
+
+    ///  Returns a String formatted as `true` => `one` and `false` => `zero`.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::SizedBitset;
+    /// let bitset = SizedBitset::from([true, true, false, false]);
+    ///
+    /// assert_eq!(bitset.to_string_with('a', 'b'), "bbaa".to_owned());
+    /// ```
+    pub fn to_string_with(&self, one: char, zero: char) -> String {
+        self.bits
+            .map(|bit| if bit { one } else { zero })
+            .iter()
+            .rev()
+            .collect::<String>()
+    }
+}
+
+impl<const N: usize> core::str::FromStr for SizedBitset<N> {
+    type Err = ConversionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(s.chars()
+            .map(|bit| match bit {
+                '0' => Ok(false),
+                '1' => Ok(true),
+                _ => Err(ConversionError::FromStr(s.to_owned())),
+            })
+            .rev()
+            .collect::<Result<Vec<_>, _>>()?
+            .as_slice()
+            .try_into()?)
+    }
+}
+
+impl<const N: usize> core::fmt::Display for SizedBitset<N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{}",
+            self.bits
+                .map(|bit| if bit { '1' } else { '0' })
+                .iter()
+                .rev()
+                .collect::<String>()
+        )
+    }
+}
+
+/// Element access
+impl<const N: usize> SizedBitset<N> {
+    /// Checks if all bits are set to true.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::SizedBitset;
+    /// let bitset = SizedBitset::from_const([true, true, true, true]);
+    /// assert!(bitset.all());
+    /// ```
+    pub fn all(&self) -> bool {
+        self.bits.iter().all(|bit| *bit)
+    }
+
+    /// Checks if any bits are set to true.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::SizedBitset;
+    /// let bitset = SizedBitset::from_const([false, false, true, false]);
+    /// assert!(bitset.any());
+    /// ```
+    pub fn any(&self) -> bool {
+        self.bits.iter().any(|bit| *bit)
+    }
+
+    /// Checks if none bits are set to true.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::SizedBitset;
+    /// let bitset = SizedBitset::from_const([false, false, false, false]);
+    /// assert!(bitset.none());
+    /// ```
+    pub fn none(&self) -> bool {
+        self.bits.iter().all(|bit| !*bit)
+    }
+
+    /// Returns the number of bits set to true.
+    ///
+    /// ```
+    /// use sized_bitset::SizedBitset;
+    /// let bitset = SizedBitset::from_const([true, false, true, false]);
+    /// assert_eq!(bitset.count(), 2);
+    /// ```
+    pub fn count(&self) -> usize {
+        self.bits.iter().filter(|bit| **bit).count()
+    }
+
+    /// Returns the number of bits set to true below the index `i`.
+    ///
+    /// Panics if `i > N`.
+    /// Use [RankSelect](crate::RankSelect) for repeated queries on large bitsets.
+    ///
+    /// ```
+    /// use sized_bitset::SizedBitset;
+    /// let bitset = SizedBitset::from_const([true, false, true, true]);
+    /// assert_eq!(bitset.rank(0), 0);
+    /// assert_eq!(bitset.rank(3), 2);
+    /// assert_eq!(bitset.rank(4), 3);
+    /// ```
+    pub fn rank(&self, i: usize) -> usize {
+        self.bits[..i].iter().filter(|bit| **bit).count()
+    }
+
+    /// Returns the index of the `k`-th (0-origin) bit set to true.
+    ///
+    /// Returns `None` if fewer than `k + 1` bits are set.
+    ///
+    /// ```
+    /// use sized_bitset::SizedBitset;
+    /// let bitset = SizedBitset::from_const([true, false, true, true]);
+    /// assert_eq!(bitset.select(0), Some(0));
+    /// assert_eq!(bitset.select(1), Some(2));
+    /// assert_eq!(bitset.select(3), None);
+    /// ```
+    pub fn select(&self, k: usize) -> Option<usize> {
+        self.bits.iter().positions(|bit| *bit).nth(k)
+    }
+}
+
+/// Truth tables
+impl<const N: usize> SizedBitset<N> {
+    /// Evaluates the 3-input boolean function `imm` bitwise (like AVX-512 `vpternlog`).
+    ///
+    /// The `i`-th bit of the result is the `(a[i] << 2 | b[i] << 1 | c[i])`-th bit of `imm`.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::convert::To8;
+    /// use sized_bitset::SizedBitset;
+    /// let a: SizedBitset<8> = 0b11110000.into();
+    /// let b: SizedBitset<8> = 0b11001100.into();
+    /// let c: SizedBitset<8> = 0b10101010.into();
+    ///
+    /// // a ^ b ^ c
+    /// assert_eq!(SizedBitset::ternary_logic(a, b, c, 0x96).to_u8(), 0b10010110);
+    /// // a ? b : c
+    /// assert_eq!(SizedBitset::ternary_logic(a, b, c, 0xca).to_u8(), 0b11001010);
+    /// ```
+    pub fn ternary_logic(a: Self, b: Self, c: Self, imm: u8) -> Self {
+        Self::from_fn(|i| {
+            let index = (a.bits[i] as u8) << 2 | (b.bits[i] as u8) << 1 | c.bits[i] as u8;
+            (imm >> index) & 1 == 1
+        })
+    }
+
+    /// Bitwise select, i.e. `(a & mask) | (b & !mask)` in a single pass.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::convert::To8;
+    /// use sized_bitset::SizedBitset;
+    /// let mask: SizedBitset<8> = 0b11110000.into();
+    /// let a: SizedBitset<8> = 0b10101010.into();
+    /// let b: SizedBitset<8> = 0b01010101.into();
+    /// assert_eq!(SizedBitset::mux(mask, a, b).to_u8(), 0b10100101);
+    /// ```
+    pub fn mux(mask: Self, a: Self, b: Self) -> Self {
+        Self::from_fn(|i| if mask.bits[i] { a.bits[i] } else { b.bits[i] })
+    }
+
+    /// Evaluates the `K`-input boolean function `table` bitwise.
+    ///
+    /// The `i`-th bit of the result is the `(inputs[0][i] | inputs[1][i] << 1 | ... )`-th bit of `table`,
+    /// so `ternary_logic(a, b, c, imm)` equals to `eval_truth_table(&[c, b, a], imm.into())`.
+    ///
+    /// Panics if `T` is not `2^K`.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::convert::To8;
+    /// use sized_bitset::SizedBitset;
+    /// let a: SizedBitset<8> = 0b11110000.into();
+    /// let b: SizedBitset<8> = 0b11001100.into();
+    ///
+    /// // a & !b
+    /// let table = SizedBitset::from_const([false, true, false, false]);
+    /// assert_eq!(SizedBitset::eval_truth_table(&[a, b], table).to_u8(), 0b00110000);
+    /// ```
+    pub fn eval_truth_table<const K: usize, const T: usize>(
+        inputs: &[Self; K],
+        table: SizedBitset<T>,
+    ) -> Self {
+        assert!(
+            K < usize::BITS as usize && T == 1 << K,
+            "the truth table of {K} inputs must have 2^{K} entries, but it has {T}"
+        );
+
+        Self::from_fn(|i| {
+            let index = inputs
+                .iter()
+                .enumerate()
+                .fold(0, |index, (k, input)| index | (input.bits[i] as usize) << k);
+            table.bits[index]
+        })
+    }
+}
+
+/// Similarity metrics
+impl<const N: usize> SizedBitset<N> {
+    /// Returns `true` if the number of bits set to true is odd.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::SizedBitset;
+    /// let bitset = SizedBitset::from_const([true, false, true, true]);
+    /// assert!(bitset.parity());
+    /// ```
+    pub fn parity(&self) -> bool {
+        self.bits.iter().fold(false, |parity, bit| parity ^ bit)
+    }
+
+    /// Returns the number of bits set to true in `self & other` without materializing it.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::SizedBitset;
+    /// let lhs = SizedBitset::from_const([true, true, false, false]);
+    /// let rhs = SizedBitset::from_const([true, false, true, false]);
+    /// assert_eq!(lhs.and_count(&rhs), 1);
+    /// ```
+    pub fn and_count(&self, other: &Self) -> usize {
+        self.count_with(other, |a, b| a & b)
+    }
+
+    /// Returns the number of bits set to true in `self | other` without materializing it.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::SizedBitset;
+    /// let lhs = SizedBitset::from_const([true, true, false, false]);
+    /// let rhs = SizedBitset::from_const([true, false, true, false]);
+    /// assert_eq!(lhs.or_count(&rhs), 3);
+    /// ```
+    pub fn or_count(&self, other: &Self) -> usize {
+        self.count_with(other, |a, b| a | b)
+    }
+
+    /// Returns the number of bits set to true in `self ^ other` without materializing it.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::SizedBitset;
+    /// let lhs = SizedBitset::from_const([true, true, false, false]);
+    /// let rhs = SizedBitset::from_const([true, false, true, false]);
+    /// assert_eq!(lhs.xor_count(&rhs), 2);
+    /// ```
+    pub fn xor_count(&self, other: &Self) -> usize {
+        self.count_with(other, |a, b| a ^ b)
+    }
+
+    /// Returns the number of bits set to true in `self & !other` without materializing it.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::SizedBitset;
+    /// let lhs = SizedBitset::from_const([true, true, false, false]);
+    /// let rhs = SizedBitset::from_const([true, false, true, false]);
+    /// assert_eq!(lhs.andnot_count(&rhs), 1);
+    /// ```
+    pub fn andnot_count(&self, other: &Self) -> usize {
+        self.count_with(other, |a, b| a & !b)
+    }
+
+    /// Returns the number of positions at which the bits differ.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::SizedBitset;
+    /// let lhs = SizedBitset::from_const([true, true, false, false]);
+    /// let rhs = SizedBitset::from_const([true, false, true, false]);
+    /// assert_eq!(lhs.hamming_distance(&rhs), 2);
+    /// ```
+    pub fn hamming_distance(&self, other: &Self) -> usize {
+        self.xor_count(other)
+    }
+
+    /// Returns the number of equal bits counted from the highest bit until the first difference.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::SizedBitset;
+    /// let lhs: SizedBitset<8> = 0b10110100.into();
+    /// let rhs: SizedBitset<8> = 0b10111111.into();
+    /// assert_eq!(lhs.common_prefix_len(&rhs), 4);
+    /// ```
+    pub fn common_prefix_len(&self, other: &Self) -> usize {
+        self.bits
+            .iter()
+            .rev()
+            .zip(other.bits.iter().rev())
+            .take_while(|(a, b)| a == b)
+            .count()
+    }
+
+    /// Returns the Jaccard index `|self & other| / |self | other|`.
+    ///
+    /// Returns `1.0` if both are empty.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::SizedBitset;
+    /// let lhs = SizedBitset::from_const([true, true, false, false]);
+    /// let rhs = SizedBitset::from_const([true, false, true, false]);
+    /// assert_eq!(lhs.jaccard(&rhs), 1.0 / 3.0);
+    /// ```
+    pub fn jaccard(&self, other: &Self) -> f64 {
+        let (and, or) = self
+            .bits
+            .iter()
+            .zip(other.bits.iter())
+            .fold((0usize, 0usize), |(and, or), (a, b)| {
+                (and + (a & b) as usize, or + (a | b) as usize)
+            });
+
+        if or == 0 {
+            1.0
+        } else {
+            and as f64 / or as f64
+        }
+    }
+
+    /// Returns the Sørensen–Dice coefficient `2 |self & other| / (|self| + |other|)`.
+    ///
+    /// Returns `1.0` if both are empty.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::SizedBitset;
+    /// let lhs = SizedBitset::from_const([true, true, false, false]);
+    /// let rhs = SizedBitset::from_const([true, false, true, false]);
+    /// assert_eq!(lhs.dice(&rhs), 0.5);
+    /// ```
+    pub fn dice(&self, other: &Self) -> f64 {
+        let (and, total) = self
+            .bits
+            .iter()
+            .zip(other.bits.iter())
+            .fold((0usize, 0usize), |(and, total), (a, b)| {
+                (and + (a & b) as usize, total + *a as usize + *b as usize)
+            });
+
+        if total == 0 {
+            1.0
+        } else {
+            (2 * and) as f64 / total as f64
+        }
+    }
+
+    /// Returns the Tanimoto coefficient `|self & other| / (|self| + |other| - |self & other|)`.
+    ///
+    /// For bitsets this equals to the Jaccard index.
+    /// Returns `1.0` if both are empty.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::SizedBitset;
+    /// let lhs = SizedBitset::from_const([true, true, false, false]);
+    /// let rhs = SizedBitset::from_const([true, false, true, false]);
+    /// assert_eq!(lhs.tanimoto(&rhs), 1.0 / 3.0);
+    /// ```
+    pub fn tanimoto(&self, other: &Self) -> f64 {
+        self.jaccard(other)
+    }
+
+    fn count_with(&self, other: &Self, f: impl Fn(bool, bool) -> bool) -> usize {
+        self.bits
+            .iter()
+            .zip(other.bits.iter())
+            .filter(|(a, b)| f(**a, **b))
+            .count()
+    }
+}
+
+/// Change detection
+impl<const N: usize> SizedBitset<N> {
+    /// Returns an iterator over the changed positions from `self` to `other` as `(index, old, new)`, from the lowest bit.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::SizedBitset;
+    /// let old = SizedBitset::from_const([true, true, false, false]);
+    /// let new = SizedBitset::from_const([true, false, true, false]);
+    /// assert_eq!(
+    ///     old.diff(&new).collect::<Vec<_>>(),
+    ///     vec![(1, true, false), (2, false, true)]
+    /// );
+    /// ```
+    pub fn diff<'a>(&'a self, other: &'a Self) -> impl Iterator<Item = (usize, bool, bool)> + 'a {
+        self.bits
+            .iter()
+            .zip(other.bits.iter())
+            .enumerate()
+            .filter(|(_, (old, new))| old != new)
+            .map(|(i, (old, new))| (i, *old, *new))
+    }
+
+    /// Returns the mask of bits that went from false (in `prev`) to true (in `self`).
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::SizedBitset;
+    /// let prev = SizedBitset::from_const([true, true, false, false]);
+    /// let current = SizedBitset::from_const([true, false, true, false]);
+    /// assert_eq!(current.rising_edges(&prev), SizedBitset::from_const([false, false, true, false]));
+    /// ```
+    pub fn rising_edges(&self, prev: &Self) -> Self {
+        self.zip_with(prev, |current, prev| current && !prev)
+    }
+
+    /// Returns the mask of bits that went from true (in `prev`) to false (in `self`).
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::SizedBitset;
+    /// let prev = SizedBitset::from_const([true, true, false, false]);
+    /// let current = SizedBitset::from_const([true, false, true, false]);
+    /// assert_eq!(current.falling_edges(&prev), SizedBitset::from_const([false, true, false, false]));
+    /// ```
+    pub fn falling_edges(&self, prev: &Self) -> Self {
+        self.zip_with(prev, |current, prev| !current && prev)
+    }
+
+    /// Returns the change mask from `self` to `target`, i.e. the bits that differ.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::SizedBitset;
+    /// let base = SizedBitset::from_const([true, true, false, false]);
+    /// let target = SizedBitset::from_const([true, false, true, false]);
+    /// let patch = base.patch_to(&target);
+    /// assert_eq!(patch, SizedBitset::from_const([false, true, true, false]));
+    /// assert_eq!(base.apply_patch(&patch), target);
+    /// ```
+    pub fn patch_to(&self, target: &Self) -> Self {
+        self.zip_with(target, |base, target| base ^ target)
+    }
+
+    /// Returns `self` with the bits in the change mask `patch` flipped.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::SizedBitset;
+    /// let base = SizedBitset::from_const([true, true, false, false]);
+    /// let patch = SizedBitset::from_const([false, true, true, false]);
+    /// assert_eq!(base.apply_patch(&patch), SizedBitset::from_const([true, false, true, false]));
+    /// ```
+    pub fn apply_patch(&self, patch: &Self) -> Self {
+        self.zip_with(patch, |base, flip| base ^ flip)
+    }
+}
+
+/// Field access
+impl<const N: usize> SizedBitset<N> {
+    /// Reads the bits in `range` as an unsigned integer, whose lowest bit is `range.start`.
+    ///
+    /// Panics if `range` is out of bounds or wider than 128 bits.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::SizedBitset;
+    /// let bitset: SizedBitset<16> = 0b0000_1011_0110_0000_u16.into();
+    /// assert_eq!(bitset.get_bits(5..12), 0b101_1011);
+    /// ```
+    pub fn get_bits(&self, range: impl RangeBounds<usize>) -> u128 {
+        let field = &self.bits[(range.start_bound().cloned(), range.end_bound().cloned())];
+        assert!(
+            field.len() <= 128,
+            "the field must be at most 128 bits, but it is {}",
+            field.len()
+        );
+
+        field
+            .iter()
+            .rev()
+            .fold(0, |value, bit| (value << 1) | (*bit as u128))
+    }
+
+    /// Writes `value` into the bits in `range`, whose lowest bit is `range.start`.
+    ///
+    /// Returns an error if `value` does not fit in the range.
+    /// Panics if `range` is out of bounds.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::{convert::To16, SizedBitset};
+    /// let mut bitset = SizedBitset::<16>::new();
+    /// bitset.set_bits(4..8, 0b1011).unwrap();
+    /// assert_eq!(bitset.to_u16(), 0b0000_0000_1011_0000);
+    ///
+    /// assert!(bitset.set_bits(4..8, 0b10000).is_err());
+    /// ```
+    pub fn set_bits(
+        &mut self,
+        range: impl RangeBounds<usize>,
+        value: u128,
+    ) -> Result<(), FieldError> {
+        let field = &mut self.bits[(range.start_bound().cloned(), range.end_bound().cloned())];
+        let width = field.len();
+
+        if width < 128 && value >> width != 0 {
+            return Err(FieldError::Overflow { value, width });
+        }
+
+        for (i, bit) in field.iter_mut().enumerate() {
+            *bit = i < 128 && (value >> i) & 1 == 1;
+        }
+
+        Ok(())
+    }
+
+    /// Views `self` as `N / K` unsigned lanes of `K` bits.
+    ///
+    /// Panics if `K` is not in `1..=128` or `N` is not a multiple of `K`.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::SizedBitset;
+    /// let bitset: SizedBitset<8> = 0b1001_0011_u8.into();
+    /// let lanes = bitset.lanes::<4>();
+    /// assert_eq!(lanes.lane(0), 0b0011);
+    /// assert_eq!(lanes.lane(1), 0b1001);
+    /// ```
+    pub fn lanes<const K: usize>(self) -> Lanes<N, K> {
+        Lanes::new(self)
+    }
+}
+
+/// Run-length queries
+impl<const N: usize> SizedBitset<N> {
+    /// Returns an iterator over maximal runs of equal bits as `(start, len, value)`, from the lowest bit.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::SizedBitset;
+    /// let bitset = SizedBitset::from_const([true, true, false, true]);
+    /// assert_eq!(
+    ///     bitset.runs().collect::<Vec<_>>(),
+    ///     vec![(0, 2, true), (2, 1, false), (3, 1, true)]
+    /// );
+    /// ```
+    pub fn runs(&self) -> impl Iterator<Item = (usize, usize, bool)> + '_ {
+        self.bits
+            .iter()
+            .dedup_with_count()
+            .scan(0, |start, (len, bit)| {
+                let run = (*start, len, *bit);
+                *start += len;
+                Some(run)
+            })
+    }
+
+    /// Returns `(start, len)` of the first longest run of bits equal to `value`.
+    ///
+    /// Returns `None` if no bit is equal to `value`.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::SizedBitset;
+    /// let bitset = SizedBitset::from_const([false, true, false, false, true, false, false]);
+    /// assert_eq!(bitset.longest_run(false), Some((2, 2)));
+    /// assert_eq!(bitset.longest_run(true), Some((1, 1)));
+    /// ```
+    pub fn longest_run(&self, value: bool) -> Option<(usize, usize)> {
+        self.runs()
+            .filter(|(_, _, bit)| *bit == value)
+            .fold(None, |longest, (start, len, _)| match longest {
+                Some((_, max)) if max >= len => longest,
+                _ => Some((start, len)),
+            })
+    }
+
+    /// Returns the start of the first run of at least `k` bits equal to `value` (first-fit).
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::SizedBitset;
+    /// let bitset = SizedBitset::from_const([false, false, true, false, false, false, true, false]);
+    /// assert_eq!(bitset.find_run(false, 2), Some(0));
+    /// assert_eq!(bitset.find_run(false, 3), Some(3));
+    /// assert_eq!(bitset.find_run(false, 4), None);
+    /// ```
+    pub fn find_run(&self, value: bool, k: usize) -> Option<usize> {
+        if k == 0 {
+            return Some(0);
+        }
+
+        self.runs()
+            .find(|(_, len, bit)| *bit == value && *len >= k)
+            .map(|(start, _, _)| start)
+    }
+
+    /// Returns the start of the shortest run of at least `k` bits equal to `value` (best-fit).
+    ///
+    /// Ties are broken by taking the lowest run.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::SizedBitset;
+    /// let bitset = SizedBitset::from_const([false, false, false, true, false, false, true, false]);
+    /// assert_eq!(bitset.find_run_best_fit(false, 2), Some(4));
+    /// assert_eq!(bitset.find_run_best_fit(false, 3), Some(0));
+    /// assert_eq!(bitset.find_run_best_fit(false, 4), None);
+    /// ```
+    pub fn find_run_best_fit(&self, value: bool, k: usize) -> Option<usize> {
+        if k == 0 {
+            return Some(0);
+        }
+
+        self.runs()
+            .filter(|(_, len, bit)| *bit == value && *len >= k)
+            .min_by_key(|(_, len, _)| *len)
+            .map(|(start, _, _)| start)
+    }
+}
+
+/// Combinators
+impl<const N: usize> SizedBitset<N> {
+    /// Returns [SizedBitset] whose `i`-th bit is `f(i, self[i])`.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::SizedBitset;
+    /// let bitset = SizedBitset::from_const([true, true, false, false]);
+    /// assert_eq!(
+    ///     bitset.map(|i, bit| bit ^ (i % 2 == 0)),
+    ///     SizedBitset::from_const([false, true, true, false])
+    /// );
+    /// ```
+    pub fn map(&self, mut f: impl FnMut(usize, bool) -> bool) -> Self {
+        Self::from_fn(|i| f(i, self.bits[i]))
+    }
+
+    /// Returns [SizedBitset] whose `i`-th bit is `f(self[i], other[i])`.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::SizedBitset;
+    /// let lhs = SizedBitset::from_const([true, true, false, false]);
+    /// let rhs = SizedBitset::from_const([true, false, true, false]);
+    /// assert_eq!(
+    ///     lhs.zip_with(&rhs, |a, b| a && !b),
+    ///     SizedBitset::from_const([false, true, false, false])
+    /// );
+    /// ```
+    pub fn zip_with(&self, other: &Self, mut f: impl FnMut(bool, bool) -> bool) -> Self {
+        Self::from_fn(|i| f(self.bits[i], other.bits[i]))
+    }
+
+    /// Folds every bit into an accumulator by applying `f(acc, i, self[i])`, from the lowest bit.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::SizedBitset;
+    /// let bitset = SizedBitset::from_const([true, false, true, true]);
+    /// assert_eq!(bitset.fold(0, |sum, i, bit| if bit { sum + i } else { sum }), 5);
+    /// ```
+    pub fn fold<B>(&self, init: B, mut f: impl FnMut(B, usize, bool) -> B) -> B {
+        self.bits
+            .iter()
+            .enumerate()
+            .fold(init, |acc, (i, bit)| f(acc, i, *bit))
+    }
+
+    /// Calls `f` with the index of each bit set to true, from the lowest bit,
+    /// stopping at the first [ControlFlow::Break](core::ops::ControlFlow::Break).
+    ///
+    /// # Example
+    /// ```
+    /// use std::ops::ControlFlow;
+    ///
+    /// use sized_bitset::SizedBitset;
+    /// let bitset = SizedBitset::from_const([false, true, false, true]);
+    /// let found = bitset.try_for_each_one(|i| {
+    ///     if i > 2 {
+    ///         ControlFlow::Break(i)
+    ///     } else {
+    ///         ControlFlow::Continue(())
+    ///     }
+    /// });
+    /// assert_eq!(found, ControlFlow::Break(3));
+    /// ```
+    pub fn try_for_each_one<B>(
+        &self,
+        f: impl FnMut(usize) -> core::ops::ControlFlow<B>,
+    ) -> core::ops::ControlFlow<B> {
+        self.bits.iter().positions(|bit| *bit).try_for_each(f)
+    }
+}
+
+/// Modifiers
+impl<const N: usize> SizedBitset<N> {
+    /// Returns [SizedBitset] that all bits are flipped.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::SizedBitset;
+    /// let bitset = SizedBitset::from_const([false, false, false, false]);
+    /// assert_eq!(bitset.flipped(), SizedBitset::from_const([true, true, true, true]));
+    /// ```
+    pub fn flipped(&self) -> Self {
+        Self {
+            bits: self.bits.map(std::ops::Not::not),
+        }
+    }
+
+    /// Flips all bits.
+    ///
+    /// ```
+    /// use sized_bitset::SizedBitset;
+    /// let mut bitset = SizedBitset::from_const([false, false, false, false]);
+    /// bitset.flip();
+    /// assert_eq!(bitset, SizedBitset::from_const([true, true, true, true]));
+    /// ```
+    pub fn flip(&mut self) {
+        self.bits = self.bits.map(std::ops::Not::not);
+    }
+
+    /// Sets the bit for the specified index to true.
+    ///
+    /// ```
+    /// use sized_bitset::SizedBitset;
+    /// let mut bitset = SizedBitset::from_const([false, false, false, false]);
+    /// bitset.set(1);
+    /// assert_eq!(bitset, SizedBitset::from_const([false, true, false, false]));
+    /// ```
+    pub fn set(&mut self, index: usize) {
+        self.bits[index] = true;
+    }
+
+    /// Sets all bits to true.
+    ///
+    /// ```
+    /// use sized_bitset::SizedBitset;
+    /// let mut bitset = SizedBitset::from_const([false, false, false, false]);
+    /// bitset.set_all();
+    /// assert_eq!(bitset, SizedBitset::from_const([true, true, true, true]));
+    /// ```
+    pub fn set_all(&mut self) {
+        self.bits = self.bits.map(|_| true);
+    }
+
+    /// Sets the bit for the specified index to false.
+    ///
+    /// ```
+    /// use sized_bitset::SizedBitset;
+    /// let mut bitset = SizedBitset::from_const([true, true, true, true]);
+    /// bitset.reset(1);
+    /// assert_eq!(bitset, SizedBitset::from_const([true, false, true, true]));
+    /// ```
+    pub fn reset(&mut self, index: usize) {
+        self.bits[index] = false;
+    }
+
+    /// Sets all bits to false.
+    ///
+    /// ```
+    /// use sized_bitset::SizedBitset;
+    /// let mut bitset = SizedBitset::from_const([true, true, true, true]);
+    /// bitset.reset_all();
+    /// assert_eq!(bitset, SizedBitset::from_const([false, false, false, false]));
+    /// ```
+    pub fn reset_all(&mut self) {
+        self.bits = self.bits.map(|_| false);
+    }
+
+    /// Computes the result of bitwise left-rotating the bits of `self` by `s` positions.
+    /// This operation is also known as a [left circular shift](https://en.wikipedia.org/wiki/Circular_shift).
+    ///
+    /// ```
+    /// use sized_bitset::convert::To8;
+    /// use sized_bitset::SizedBitset;
+    /// let bitset: SizedBitset<8> = 0b00011101.into();
+    /// assert_eq!(bitset.rotl(2).to_u8(), 0b01110100);
+    /// ```
+    pub fn rotl(&self, s: usize) -> Self {
+        let r = s % N;
+
+        if r == 0 {
+            *self
+        } else {
+            (*self << r) | (*self >> (N - r))
+        }
+    }
+
+    /// Computes the result of bitwise right-rotating the bits of `self` by `s` positions.
+    /// This operation is also known as a [right circular shift](https://en.wikipedia.org/wiki/Circular_shift).
+    ///
+    /// ```
+    /// use sized_bitset::convert::To8;
+    /// use sized_bitset::SizedBitset;
+    /// let bitset: SizedBitset<8> = 0b00011101.into();
+    /// assert_eq!(bitset.rotr(2).to_u8(), 0b01000111);
+    /// ```
+    pub fn rotr(&self, s: usize) -> Self {
+        let r = s % N;
+
+        if r == 0 {
+            *self
+        } else {
+            (*self >> r) | (*self << (N - r))
+        }
+    }
+
+    /// Checked shift left.
+    /// Returns `None` if any set bit would be shifted out.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::convert::To8;
+    /// use sized_bitset::SizedBitset;
+    /// let bitset: SizedBitset<8> = 0b00011101.into();
+    /// assert_eq!(bitset.checked_shl(3).map(|b| b.to_u8()), Some(0b11101000));
+    /// assert_eq!(bitset.checked_shl(4), None);
+    /// ```
+    pub fn checked_shl(&self, rhs: usize) -> Option<Self> {
+        if self.bits[N.saturating_sub(rhs)..].iter().any(|bit| *bit) {
+            None
+        } else {
+            Some(*self << rhs)
+        }
+    }
+
+    /// Checked shift right.
+    /// Returns `None` if any set bit would be shifted out.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::convert::To8;
+    /// use sized_bitset::SizedBitset;
+    /// let bitset: SizedBitset<8> = 0b10111000.into();
+    /// assert_eq!(bitset.checked_shr(3).map(|b| b.to_u8()), Some(0b00010111));
+    /// assert_eq!(bitset.checked_shr(4), None);
+    /// ```
+    pub fn checked_shr(&self, rhs: usize) -> Option<Self> {
+        if self.bits[..rhs.min(N)].iter().any(|bit| *bit) {
+            None
+        } else {
+            Some(*self >> rhs)
+        }
+    }
+
+    /// Shifts left and returns a tuple of the shifted bitset along with the bits shifted out.
+    ///
+    /// The second element is the upper half of the `2N`-bit result,
+    /// i.e. the bits shifted out of the top are kept in their order starting from the lowest bit.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::convert::To8;
+    /// use sized_bitset::SizedBitset;
+    /// let bitset: SizedBitset<8> = 0b10111001.into();
+    /// let (shifted, overflow) = bitset.overflowing_shl(3);
+    /// assert_eq!(shifted.to_u8(), 0b11001000);
+    /// assert_eq!(overflow.to_u8(), 0b00000101);
+    /// ```
+    pub fn overflowing_shl(&self, rhs: usize) -> (Self, Self) {
+        let overflow = if rhs <= N {
+            *self >> (N - rhs)
+        } else {
+            *self << (rhs - N)
+        };
+
+        (*self << rhs, overflow)
+    }
+
+    /// Shifts right and returns a tuple of the shifted bitset along with the bits shifted out.
+    ///
+    /// The second element is the lower half of the `2N`-bit result,
+    /// i.e. the bits shifted out of the bottom are kept in their order ending at the highest bit.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::convert::To8;
+    /// use sized_bitset::SizedBitset;
+    /// let bitset: SizedBitset<8> = 0b10111001.into();
+    /// let (shifted, overflow) = bitset.overflowing_shr(3);
+    /// assert_eq!(shifted.to_u8(), 0b00010111);
+    /// assert_eq!(overflow.to_u8(), 0b00100000);
+    /// ```
+    pub fn overflowing_shr(&self, rhs: usize) -> (Self, Self) {
+        let overflow = if rhs <= N {
+            *self << (N - rhs)
+        } else {
+            *self >> (rhs - N)
+        };
+
+        (*self >> rhs, overflow)
+    }
+
+    /// Shifts left by one, sets the lowest bit to `bit` and returns the bit shifted out of the top.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::convert::To8;
+    /// use sized_bitset::SizedBitset;
+    /// let mut bitset: SizedBitset<8> = 0b10110000.into();
+    /// assert!(bitset.shl_in(true));
+    /// assert_eq!(bitset.to_u8(), 0b01100001);
+    /// ```
+    pub fn shl_in(&mut self, bit: bool) -> bool {
+        let out = self.bits[N - 1];
+        *self <<= 1;
+        self.bits[0] = bit;
+        out
+    }
+
+    /// Shifts right by one, sets the highest bit to `bit` and returns the bit shifted out of the bottom.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::convert::To8;
+    /// use sized_bitset::SizedBitset;
+    /// let mut bitset: SizedBitset<8> = 0b00001101.into();
+    /// assert!(bitset.shr_in(false));
+    /// assert_eq!(bitset.to_u8(), 0b00000110);
+    /// ```
+    pub fn shr_in(&mut self, bit: bool) -> bool {
+        let out = self.bits[0];
+        *self >>= 1;
+        self.bits[N - 1] = bit;
+        out
+    }
+
+    /// Double-width shift left (like x86 `shld`).
+    ///
+    /// Treats `hi` and `lo` as one `2N`-bit value, shifts it left by `s` and returns the upper `N` bits.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::convert::To8;
+    /// use sized_bitset::SizedBitset;
+    /// let hi: SizedBitset<8> = 0b00001111.into();
+    /// let lo: SizedBitset<8> = 0b10100000.into();
+    /// assert_eq!(SizedBitset::funnel_shl(hi, lo, 3).to_u8(), 0b01111101);
+    /// ```
+    pub fn funnel_shl(hi: Self, lo: Self, s: usize) -> Self {
+        let concat = |i: usize| if i < N { lo.bits[i] } else { hi.bits[i - N] };
+
+        Self {
+            bits: core::array::from_fn(|i| (i + N).checked_sub(s).map_or(false, concat)),
+        }
+    }
+
+    /// Double-width shift right (like x86 `shrd`).
+    ///
+    /// Treats `hi` and `lo` as one `2N`-bit value, shifts it right by `s` and returns the lower `N` bits.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::convert::To8;
+    /// use sized_bitset::SizedBitset;
+    /// let hi: SizedBitset<8> = 0b00000101.into();
+    /// let lo: SizedBitset<8> = 0b11110000.into();
+    /// assert_eq!(SizedBitset::funnel_shr(hi, lo, 3).to_u8(), 0b10111110);
+    /// ```
+    pub fn funnel_shr(hi: Self, lo: Self, s: usize) -> Self {
+        let concat = |i: usize| if i < N { lo.bits[i] } else { hi.bits[i - N] };
+
+        Self {
+            bits: core::array::from_fn(|i| {
+                let src = i + s;
+                src < 2 * N && concat(src)
+            }),
+        }
+    }
+
+    /// Reverses the order of bits.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::convert::To8;
+    /// use sized_bitset::SizedBitset;
+    /// let bitset: SizedBitset<8> = 0b00010111.into();
+    /// assert_eq!(bitset.reverse_bits().to_u8(), 0b11101000);
+    /// ```
+    pub fn reverse_bits(&self) -> Self {
+        let mut bits = self.bits;
+        bits.reverse();
+        Self { bits }
+    }
+
+    /// Reverses the byte order.
+    ///
+    /// Panics if `N` is not a multiple of 8.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::convert::To16;
+    /// use sized_bitset::SizedBitset;
+    /// let bitset: SizedBitset<16> = 0x12ab_u16.into();
+    /// assert_eq!(bitset.swap_bytes().to_u16(), 0xab12);
+    /// ```
+    pub fn swap_bytes(&self) -> Self {
+        assert!(
+            N % 8 == 0,
+            "the size must be a multiple of 8, but it is {N}"
+        );
+
+        Self::from_fn(|i| self.bits[N - 8 - (i - i % 8) + i % 8])
+    }
+
+    /// Reverses the order of bits within each group of `G` bits.
+    ///
+    /// Panics if `N` is not a multiple of `G`.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::convert::To16;
+    /// use sized_bitset::SizedBitset;
+    /// let bitset: SizedBitset<16> = 0b0001_0011_0111_1111_u16.into();
+    /// assert_eq!(bitset.reverse_within::<4>().to_u16(), 0b1000_1100_1110_1111);
+    /// ```
+    pub fn reverse_within<const G: usize>(&self) -> Self {
+        assert!(
+            G != 0 && N % G == 0,
+            "the size must be a multiple of {G}, but it is {N}"
+        );
+
+        Self::from_fn(|i| self.bits[i - i % G + (G - 1 - i % G)])
+    }
+}
+
+/// Gray code
+impl<const N: usize> SizedBitset<N> {
+    /// Encodes `self` into the binary-reflected Gray code.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::convert::To8;
+    /// use sized_bitset::SizedBitset;
+    /// let bitset: SizedBitset<8> = 0b00000111.into();
+    /// assert_eq!(bitset.to_gray().to_u8(), 0b00000100);
+    /// ```
+    pub fn to_gray(&self) -> Self {
+        *self ^ (*self >> 1)
+    }
+
+    /// Decodes the binary-reflected Gray code `gray`.
     ///
     /// # Example
     /// ```
+    /// use sized_bitset::convert::To8;
     /// use sized_bitset::SizedBitset;
-    /// const BITSET: SizedBitset<4> = SizedBitset::from_const([true, true, false, false]);
+    /// let gray: SizedBitset<8> = 0b00000100.into();
+    /// assert_eq!(SizedBitset::from_gray(gray).to_u8(), 0b00000111);
     /// ```
-    pub const fn from_const(bits: [bool; N]) -> Self {
-        Self { bits }
-    }
-}
-
-impl<T: Into<bool> + Copy, const N: usize> From<[T; N]> for SizedBitset<N> {
-    /// Make [SizedBitset] from convertible array.
-    fn from(from: [T; N]) -> Self {
-        Self {
-            bits: from.map(|bit| bit.into()),
+    pub fn from_gray(gray: Self) -> Self {
+        let mut bits = gray.bits;
+        for i in (0..N.saturating_sub(1)).rev() {
+            bits[i] ^= bits[i + 1];
         }
+        Self { bits }
     }
-}
-
-impl<T: Into<bool> + Copy, const N: usize> TryFrom<&[T]> for SizedBitset<N> {
-    type Error = core::array::TryFromSliceError;
-
-    /// Try make [SizedBitset] from convertible slice.
-    fn try_from(from: &[T]) -> Result<Self, Self::Error> {
-        Ok(Self {
-            bits: from
-                .iter()
-                .map(|b| (*b).into())
-                .collect_vec()
-                .as_slice()
-                .try_into()?,
-        })
-    }
-}
 
-impl<const N: usize> Default for SizedBitset<N> {
-    /// Returns [SizedBitset] that all bits is false
-    fn default() -> Self {
-        Self::new()
+    /// Returns an iterator over all `2^N` bitsets in Gray code order,
+    /// i.e. exactly one bit changes per step.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::convert::To8;
+    /// use sized_bitset::SizedBitset;
+    /// let codes = SizedBitset::<3>::gray_codes().map(|bitset| bitset.to_u8()).collect::<Vec<_>>();
+    /// assert_eq!(codes, vec![0b000, 0b001, 0b011, 0b010, 0b110, 0b111, 0b101, 0b100]);
+    /// ```
+    pub fn gray_codes() -> GrayCodes<N> {
+        GrayCodes::new()
     }
 }
 
+/// Parallel bit deposit and extract
 impl<const N: usize> SizedBitset<N> {
-    /// Returns [SizedBitset] that all bits is false
+    /// Parallel bit deposit (like BMI2 `pdep`).
+    ///
+    /// Scatters the low bits of `self` to the positions of the bits set to true in `mask`, from the lowest bit.
     ///
     /// # Example
     /// ```
-    /// use sized_bitset::{convert::*, SizedBitset};
-    /// let bitset = SizedBitset::<8>::new();
-    ///
-    /// assert_eq!(bitset.to_u8(), 0b00000000);
+    /// use sized_bitset::convert::To8;
+    /// use sized_bitset::SizedBitset;
+    /// let bitset: SizedBitset<8> = 0b00000101.into();
+    /// let mask: SizedBitset<8> = 0b01101010.into();
+    /// assert_eq!(bitset.deposit(&mask).to_u8(), 0b00100010);
     /// ```
-    pub fn new() -> Self {
-        Self {
-            bits: unsafe {
-                (0..N)
-                    .map(|_| false)
-                    .collect_vec()
-                    .as_slice()
-                    .try_into()
-                    .unwrap_unchecked()
-            },
+    pub fn deposit(&self, mask: &Self) -> Self {
+        #[cfg(target_arch = "x86_64")]
+        if is_x86_feature_detected!("bmi2") {
+            return unsafe { self.deposit_bmi2(mask) };
         }
+
+        self.deposit_portable(mask)
     }
 
-    ///  Returns a String formatted as `true` => `one` and `false` => `zero`.
+    /// Parallel bit extract (like BMI2 `pext`).
+    ///
+    /// Gathers the bits of `self` at the positions of the bits set to true in `mask` into the low bits.
     ///
     /// # Example
     /// ```
+    /// use sized_bitset::convert::To8;
     /// use sized_bitset::SizedBitset;
-    /// let bitset = SizedBitset::from([true, true, false, false]);
-    ///
-    /// assert_eq!(bitset.to_string_with('a', 'b'), "bbaa".to_owned());
+    /// let bitset: SizedBitset<8> = 0b10100110.into();
+    /// let mask: SizedBitset<8> = 0b01101010.into();
+    /// assert_eq!(bitset.extract(&mask).to_u8(), 0b00000101);
     /// ```
-    pub fn to_string_with(&self, one: char, zero: char) -> String {
-        self.bits
-            .map(|bit| if bit { one } else { zero })
+    pub fn extract(&self, mask: &Self) -> Self {
+        #[cfg(target_arch = "x86_64")]
+        if is_x86_feature_detected!("bmi2") {
+            return unsafe { self.extract_bmi2(mask) };
+        }
+
+        self.extract_portable(mask)
+    }
+
+    fn deposit_portable(&self, mask: &Self) -> Self {
+        let mut source = self.bits.iter();
+        Self::from_fn(|i| mask.bits[i] && source.next().copied().unwrap_or(false))
+    }
+
+    fn extract_portable(&self, mask: &Self) -> Self {
+        let mut selected = mask
+            .bits
             .iter()
-            .rev()
-            .collect::<String>()
+            .zip(self.bits)
+            .filter_map(|(selected, bit)| selected.then_some(bit));
+        Self::from_fn(|_| selected.next().unwrap_or(false))
     }
-}
 
-impl<const N: usize> core::str::FromStr for SizedBitset<N> {
-    type Err = ConversionError;
+    #[cfg(target_arch = "x86_64")]
+    #[target_feature(enable = "bmi2")]
+    unsafe fn deposit_bmi2(&self, mask: &Self) -> Self {
+        let mut bits = [false; N];
+        let mut consumed = 0;
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(s.chars()
-            .map(|bit| match bit {
-                '0' => Ok(false),
-                '1' => Ok(true),
-                _ => Err(ConversionError::FromStr(s.to_owned())),
-            })
-            .rev()
-            .collect::<Result<Vec<_>, _>>()?
-            .as_slice()
-            .try_into()?)
+        for start in (0..N).step_by(64) {
+            let end = N.min(start + 64);
+            let mask = pack_u64(&mask.bits[start..end]);
+            let len = mask.count_ones() as usize;
+            let source = pack_u64(&self.bits[consumed..consumed + len]);
+
+            unpack_u64(
+                core::arch::x86_64::_pdep_u64(source, mask),
+                &mut bits[start..end],
+            );
+            consumed += len;
+        }
+
+        Self { bits }
     }
-}
 
-impl<const N: usize> core::fmt::Display for SizedBitset<N> {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(
-            f,
-            "{}",
-            self.bits
-                .map(|bit| if bit { '1' } else { '0' })
-                .iter()
-                .rev()
-                .collect::<String>()
-        )
+    #[cfg(target_arch = "x86_64")]
+    #[target_feature(enable = "bmi2")]
+    unsafe fn extract_bmi2(&self, mask: &Self) -> Self {
+        let mut bits = [false; N];
+        let mut filled = 0;
+
+        for start in (0..N).step_by(64) {
+            let end = N.min(start + 64);
+            let mask = pack_u64(&mask.bits[start..end]);
+            let len = mask.count_ones() as usize;
+            let source = pack_u64(&self.bits[start..end]);
+
+            unpack_u64(
+                core::arch::x86_64::_pext_u64(source, mask),
+                &mut bits[filled..filled + len],
+            );
+            filled += len;
+        }
+
+        Self { bits }
     }
 }
 
-/// Element access
+/// Permutation
 impl<const N: usize> SizedBitset<N> {
-    /// Checks if all bits are set to true.
+    /// Returns [SizedBitset] whose `j`-th bit is `self[table[j]]`.
+    ///
+    /// The table may repeat or omit indices (e.g. DES expansion boxes).
+    /// Use [BitPermutation](crate::BitPermutation) to validate a table once and apply it many times.
+    ///
+    /// Panics if any index in `table` is out of range.
     ///
     /// # Example
     /// ```
     /// use sized_bitset::SizedBitset;
-    /// let bitset = SizedBitset::from_const([true, true, true, true]);
-    /// assert!(bitset.all());
+    /// let bitset = SizedBitset::from_const([true, false, false, true]);
+    /// assert_eq!(
+    ///     bitset.permute(&[3, 0, 1, 3, 2, 0]),
+    ///     SizedBitset::from_const([true, true, false, true, false, true])
+    /// );
     /// ```
-    pub fn all(&self) -> bool {
-        self.bits.iter().all(|bit| *bit)
+    pub fn permute<const M: usize>(&self, table: &[usize; M]) -> SizedBitset<M> {
+        SizedBitset::from_fn(|j| self.bits[table[j]])
     }
+}
 
-    /// Checks if any bits are set to true.
+/// Bit-matrix
+impl<const N: usize> SizedBitset<N> {
+    /// Transposes the `N`x`N` bit-matrix whose `i`-th row is `rows[i]`,
+    /// i.e. `transpose(rows)[j][i] == rows[i][j]`.
+    ///
+    /// Power-of-two sizes are transposed by the log-step swap algorithm
+    /// (swapping the off-diagonal blocks of halving size), and the others by swapping each pair across the diagonal.
+    /// Since the bits are stored as `[bool; N]`, both move one bit at a time,
+    /// so the log-step path does not save work over the pairwise swap.
     ///
     /// # Example
     /// ```
     /// use sized_bitset::SizedBitset;
-    /// let bitset = SizedBitset::from_const([false, false, true, false]);
-    /// assert!(bitset.any());
+    /// let rows = [
+    ///     SizedBitset::from_const([true, true, false]),
+    ///     SizedBitset::from_const([false, false, false]),
+    ///     SizedBitset::from_const([true, false, true]),
+    /// ];
+    /// assert_eq!(
+    ///     SizedBitset::transpose(rows),
+    ///     [
+    ///         SizedBitset::from_const([true, false, true]),
+    ///         SizedBitset::from_const([true, false, false]),
+    ///         SizedBitset::from_const([false, false, true]),
+    ///     ]
+    /// );
     /// ```
-    pub fn any(&self) -> bool {
-        self.bits.iter().any(|bit| *bit)
+    pub fn transpose(mut rows: [Self; N]) -> [Self; N] {
+        if !N.is_power_of_two() {
+            for i in 0..N {
+                for j in (i + 1)..N {
+                    let bit = rows[i].bits[j];
+                    rows[i].bits[j] = rows[j].bits[i];
+                    rows[j].bits[i] = bit;
+                }
+            }
+            return rows;
+        }
+
+        let mut s = N / 2;
+        while s > 0 {
+            for i in (0..N).filter(|i| i & s == 0) {
+                for j in (0..N).filter(|j| j & s == 0) {
+                    let bit = rows[i].bits[j + s];
+                    rows[i].bits[j + s] = rows[i + s].bits[j];
+                    rows[i + s].bits[j] = bit;
+                }
+            }
+            s /= 2;
+        }
+
+        rows
     }
 
-    /// Checks if none bits are set to true.
+    /// Slices `N` samples into 8 bit-planes, i.e. `to_bit_planes(samples)[b][i]` is the `b`-th bit of `samples[i]`.
     ///
     /// # Example
     /// ```
     /// use sized_bitset::SizedBitset;
-    /// let bitset = SizedBitset::from_const([false, false, false, false]);
-    /// assert!(bitset.none());
+    /// let planes = SizedBitset::to_bit_planes(&[0b11, 0b10, 0b00]);
+    /// assert_eq!(planes[0], SizedBitset::from_const([true, false, false]));
+    /// assert_eq!(planes[1], SizedBitset::from_const([true, true, false]));
+    /// assert!(planes[2..].iter().all(|plane| plane.none()));
     /// ```
-    pub fn none(&self) -> bool {
-        self.bits.iter().all(|bit| !*bit)
+    pub fn to_bit_planes(samples: &[u8; N]) -> [Self; 8] {
+        core::array::from_fn(|b| Self::from_fn(|i| (samples[i] >> b) & 1 == 1))
     }
 
-    /// Returns the number of bits set to true.
+    /// Reassembles `N` samples from 8 bit-planes. This is the inverse of [SizedBitset::to_bit_planes].
     ///
+    /// # Example
     /// ```
     /// use sized_bitset::SizedBitset;
-    /// let bitset = SizedBitset::from_const([true, false, true, false]);
-    /// assert_eq!(bitset.count(), 2);
+    /// let planes = SizedBitset::to_bit_planes(&[0b11, 0b10, 0b00]);
+    /// assert_eq!(SizedBitset::from_bit_planes(&planes), [0b11, 0b10, 0b00]);
     /// ```
-    pub fn count(&self) -> usize {
-        self.bits.iter().filter(|bit| **bit).count()
+    pub fn from_bit_planes(planes: &[Self; 8]) -> [u8; N] {
+        core::array::from_fn(|i| {
+            planes.iter().enumerate().fold(0, |sample, (b, plane)| {
+                sample | ((plane.bits[i] as u8) << b)
+            })
+        })
     }
 }
 
-/// Modifiers
+/// GF(2) polynomial arithmetic
+///
+/// The `i`-th bit is the coefficient of `x^i`.
 impl<const N: usize> SizedBitset<N> {
-    /// Returns [SizedBitset] that all bits are flipped.
+    /// Returns the degree of the polynomial, or `None` for the zero polynomial.
     ///
     /// # Example
     /// ```
     /// use sized_bitset::SizedBitset;
-    /// let bitset = SizedBitset::from_const([false, false, false, false]);
-    /// assert_eq!(bitset.flipped(), SizedBitset::from_const([true, true, true, true]));
+    /// let poly: SizedBitset<8> = 0b00010011.into();
+    /// assert_eq!(poly.degree(), Some(4));
+    /// assert_eq!(SizedBitset::<8>::new().degree(), None);
     /// ```
-    pub fn flipped(&self) -> Self {
-        Self {
-            bits: self.bits.map(std::ops::Not::not),
-        }
+    pub fn degree(&self) -> Option<usize> {
+        self.bits.iter().rposition(|bit| *bit)
     }
 
-    /// Flips all bits.
+    /// Carry-less multiplication, i.e. the product of polynomials over GF(2).
     ///
+    /// Panics if `M < 2N - 1`, since the product can have up to `2N - 1` coefficients.
+    ///
+    /// # Example
     /// ```
+    /// use sized_bitset::convert::To16;
     /// use sized_bitset::SizedBitset;
-    /// let mut bitset = SizedBitset::from_const([false, false, false, false]);
-    /// bitset.flip();
-    /// assert_eq!(bitset, SizedBitset::from_const([true, true, true, true]));
+    /// let lhs: SizedBitset<8> = 0b00000111.into();
+    /// let rhs: SizedBitset<8> = 0b00000011.into();
+    /// assert_eq!(lhs.clmul::<16>(&rhs).to_u16(), 0b00001001);
     /// ```
-    pub fn flip(&mut self) {
-        self.bits = self.bits.map(std::ops::Not::not);
+    pub fn clmul<const M: usize>(&self, rhs: &Self) -> SizedBitset<M> {
+        assert!(
+            M + 1 >= 2 * N,
+            "the product of {N}-bit polynomials needs {} bits, but it is {M}",
+            (2 * N).saturating_sub(1)
+        );
+
+        #[cfg(target_arch = "x86_64")]
+        if is_x86_feature_detected!("pclmulqdq") {
+            return unsafe { self.clmul_pclmulqdq(rhs) };
+        }
+
+        self.clmul_portable(rhs)
     }
 
-    /// Sets the bit for the specified index to true.
+    /// Polynomial division over GF(2).
+    /// Returns the quotient and the remainder.
+    ///
+    /// Panics if `divisor` is the zero polynomial.
     ///
+    /// # Example
     /// ```
+    /// use sized_bitset::convert::To8;
     /// use sized_bitset::SizedBitset;
-    /// let mut bitset = SizedBitset::from_const([false, false, false, false]);
-    /// bitset.set(1);
-    /// assert_eq!(bitset, SizedBitset::from_const([false, true, false, false]));
+    /// // (x^4 + x + 1) = (x^2 + x) (x^2 + x + 1) + 1
+    /// let dividend: SizedBitset<8> = 0b00010011.into();
+    /// let divisor: SizedBitset<8> = 0b00000111.into();
+    /// let (quotient, remainder) = dividend.poly_div_rem(&divisor);
+    /// assert_eq!(quotient.to_u8(), 0b00000110);
+    /// assert_eq!(remainder.to_u8(), 0b00000001);
     /// ```
-    pub fn set(&mut self, index: usize) {
-        self.bits[index] = true;
+    pub fn poly_div_rem(&self, divisor: &Self) -> (Self, Self) {
+        let mut quotient = Self::new();
+        let mut remainder = *self;
+        poly_reduce(&mut remainder.bits, &divisor.bits, |i| {
+            quotient.bits[i] = true
+        });
+        (quotient, remainder)
     }
 
-    /// Sets all bits to true.
+    /// Reduces the polynomial modulo `modulus` over GF(2).
     ///
+    /// The remainder has a lower degree than `modulus`, so it fits in `M` bits.
+    /// Panics if `modulus` is the zero polynomial.
+    ///
+    /// # Example
     /// ```
+    /// use sized_bitset::convert::{To16, To8};
     /// use sized_bitset::SizedBitset;
-    /// let mut bitset = SizedBitset::from_const([false, false, false, false]);
-    /// bitset.set_all();
-    /// assert_eq!(bitset, SizedBitset::from_const([true, true, true, true]));
+    /// // x^8 mod (x^4 + x + 1) = x^2 + 1
+    /// let poly: SizedBitset<16> = 0b1_0000_0000_u16.into();
+    /// let modulus: SizedBitset<8> = 0b00010011.into();
+    /// assert_eq!(poly.poly_mod(&modulus).to_u8(), 0b00000101);
     /// ```
-    pub fn set_all(&mut self) {
-        self.bits = self.bits.map(|_| true);
+    pub fn poly_mod<const M: usize>(&self, modulus: &SizedBitset<M>) -> SizedBitset<M> {
+        let mut remainder = self.bits;
+        poly_reduce(&mut remainder, &modulus.bits, |_| ());
+        SizedBitset::from_fn(|i| i < N && remainder[i])
     }
 
-    /// Sets the bit for the specified index to false.
+    /// Returns the greatest common divisor of polynomials over GF(2).
     ///
+    /// # Example
     /// ```
+    /// use sized_bitset::convert::To8;
     /// use sized_bitset::SizedBitset;
-    /// let mut bitset = SizedBitset::from_const([true, true, true, true]);
-    /// bitset.reset(1);
-    /// assert_eq!(bitset, SizedBitset::from_const([true, false, true, true]));
+    /// // gcd((x + 1) (x^2 + x + 1), (x + 1)^2) = x + 1
+    /// let lhs: SizedBitset<8> = 0b00001001.into();
+    /// let rhs: SizedBitset<8> = 0b00000101.into();
+    /// assert_eq!(lhs.poly_gcd(&rhs).to_u8(), 0b00000011);
     /// ```
-    pub fn reset(&mut self, index: usize) {
-        self.bits[index] = false;
+    pub fn poly_gcd(&self, other: &Self) -> Self {
+        let (mut a, mut b) = (*self, *other);
+        while b.any() {
+            let (_, remainder) = a.poly_div_rem(&b);
+            a = b;
+            b = remainder;
+        }
+        a
     }
 
-    /// Sets all bits to false.
-    ///
-    /// ```
-    /// use sized_bitset::SizedBitset;
-    /// let mut bitset = SizedBitset::from_const([true, true, true, true]);
-    /// bitset.reset_all();
-    /// assert_eq!(bitset, SizedBitset::from_const([false, false, false, false]));
-    /// ```
-    pub fn reset_all(&mut self) {
-        self.bits = self.bits.map(|_| false);
+    fn clmul_portable<const M: usize>(&self, rhs: &Self) -> SizedBitset<M> {
+        let mut product = SizedBitset::<M>::new();
+        for i in (0..N).filter(|&i| self.bits[i]) {
+            for j in (0..N).filter(|&j| rhs.bits[j]) {
+                product.bits[i + j] ^= true;
+            }
+        }
+        product
     }
 
-    /// Computes the result of bitwise left-rotating the bits of `self` by `s` positions.
-    /// This operation is also known as a [left circular shift](https://en.wikipedia.org/wiki/Circular_shift).
-    ///
-    /// ```
-    /// use sized_bitset::convert::To8;
-    /// use sized_bitset::SizedBitset;
-    /// let bitset: SizedBitset<8> = 0b00011101.into();
-    /// assert_eq!(bitset.rotl(2).to_u8(), 0b01110100);
-    /// ```
-    pub fn rotl(&self, s: usize) -> Self {
-        let r = s % N;
+    #[cfg(target_arch = "x86_64")]
+    #[target_feature(enable = "pclmulqdq")]
+    unsafe fn clmul_pclmulqdq<const M: usize>(&self, rhs: &Self) -> SizedBitset<M> {
+        use core::arch::x86_64::{
+            _mm_clmulepi64_si128, _mm_cvtsi128_si64, _mm_set_epi64x, _mm_srli_si128,
+        };
 
-        if r == 0 {
-            *self
-        } else {
-            (*self << r) | (*self >> (N - r))
+        let lhs_words = self.bits.chunks(64).map(pack_u64).collect_vec();
+        let rhs_words = rhs.bits.chunks(64).map(pack_u64).collect_vec();
+        let mut product = vec![0u64; lhs_words.len() + rhs_words.len()];
+
+        for (i, lhs) in lhs_words.iter().enumerate() {
+            for (j, rhs) in rhs_words.iter().enumerate() {
+                let word = _mm_clmulepi64_si128::<0>(
+                    _mm_set_epi64x(0, *lhs as i64),
+                    _mm_set_epi64x(0, *rhs as i64),
+                );
+                product[i + j] ^= _mm_cvtsi128_si64(word) as u64;
+                product[i + j + 1] ^= _mm_cvtsi128_si64(_mm_srli_si128::<8>(word)) as u64;
+            }
         }
+
+        SizedBitset::from_fn(|k| {
+            product
+                .get(k / 64)
+                .map_or(false, |word| (word >> (k % 64)) & 1 == 1)
+        })
     }
+}
 
-    /// Computes the result of bitwise right-rotating the bits of `self` by `s` positions.
-    /// This operation is also known as a [right circular shift](https://en.wikipedia.org/wiki/Circular_shift).
-    ///
-    /// ```
-    /// use sized_bitset::convert::To8;
-    /// use sized_bitset::SizedBitset;
-    /// let bitset: SizedBitset<8> = 0b00011101.into();
-    /// assert_eq!(bitset.rotr(2).to_u8(), 0b01000111);
-    /// ```
-    pub fn rotr(&self, s: usize) -> Self {
-        let r = s % N;
+/// Reduces `dividend` modulo `divisor` in place over GF(2),
+/// calling `quotient` with the exponent of every term of the quotient.
+///
+/// Panics if `divisor` is the zero polynomial.
+fn poly_reduce(dividend: &mut [bool], divisor: &[bool], mut quotient: impl FnMut(usize)) {
+    let degree = divisor
+        .iter()
+        .rposition(|bit| *bit)
+        .expect("attempt to divide by the zero polynomial");
 
-        if r == 0 {
-            *self
-        } else {
-            (*self >> r) | (*self << (N - r))
+    for top in (degree..dividend.len()).rev() {
+        if dividend[top] {
+            let shift = top - degree;
+            for (k, bit) in divisor[..=degree].iter().enumerate() {
+                dividend[shift + k] ^= bit;
+            }
+            quotient(shift);
         }
     }
 }
 
+/// Packs up to 64 bits into `u64`, from the lowest bit.
+#[cfg(target_arch = "x86_64")]
+fn pack_u64(bits: &[bool]) -> u64 {
+    bits.iter()
+        .enumerate()
+        .fold(0, |word, (i, bit)| word | ((*bit as u64) << i))
+}
+
+/// Unpacks the low bits of `word` into `bits`, from the lowest bit.
+#[cfg(target_arch = "x86_64")]
+fn unpack_u64(word: u64, bits: &mut [bool]) {
+    for (i, bit) in bits.iter_mut().enumerate() {
+        *bit = (word >> i) & 1 == 1;
+    }
+}
+
 impl<const N: usize> core::ops::BitAnd for SizedBitset<N> {
     type Output = Self;
 
@@ -515,3 +1913,48 @@ pub mod convert {
 }
 
 sized_bitset_macros::generate_num_traits!();
+sized_bitset_macros::generate_interleave!();
+
+#[cfg(all(test, target_arch = "x86_64"))]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::SizedBitset;
+
+    fn bitset<const N: usize>() -> impl Strategy<Value = SizedBitset<N>> {
+        proptest::collection::vec(proptest::bool::ANY, N)
+            .prop_map(|bits| SizedBitset::try_from(bits.as_slice()).unwrap())
+    }
+
+    proptest! {
+        #[test]
+        fn deposit_extract_bmi2(bitset in bitset::<150>(), mask in bitset::<150>()) {
+            if is_x86_feature_detected!("bmi2") {
+                prop_assert_eq!(
+                    unsafe { bitset.deposit_bmi2(&mask) },
+                    bitset.deposit_portable(&mask)
+                );
+                prop_assert_eq!(
+                    unsafe { bitset.extract_bmi2(&mask) },
+                    bitset.extract_portable(&mask)
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn clmul_pclmulqdq(lhs in bitset::<150>(), rhs in bitset::<150>()) {
+            if is_x86_feature_detected!("pclmulqdq") {
+                prop_assert_eq!(
+                    unsafe { lhs.clmul_pclmulqdq::<299>(&rhs) },
+                    lhs.clmul_portable::<299>(&rhs)
+                );
+                prop_assert_eq!(
+                    unsafe { lhs.clmul_pclmulqdq::<320>(&rhs) },
+                    lhs.clmul_portable::<320>(&rhs)
+                );
+            }
+        }
+    }
+}
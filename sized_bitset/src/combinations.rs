@@ -0,0 +1,142 @@
+use crate::{error::CombinationError, SizedBitset};
+
+/// An iterator over all [SizedBitset]s with exactly `k` bits set to true, in colexicographic order.
+///
+/// This struct is created by [SizedBitset::combinations].
+#[derive(Debug, Clone)]
+pub struct Combinations<const N: usize> {
+    next: Option<SizedBitset<N>>,
+}
+
+impl<const N: usize> Combinations<N> {
+    /// Returns an iterator starting from the lowest `k` bits set to true.
+    pub fn new(k: usize) -> Self {
+        Self {
+            next: (k <= N).then(|| SizedBitset::mask_low(k)),
+        }
+    }
+}
+
+impl<const N: usize> Iterator for Combinations<N> {
+    type Item = SizedBitset<N>;
+
+    /// Gosper's hack: moves the highest bit of the lowest run up by one
+    /// and the rest of the run down to the bottom.
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next?;
+
+        self.next = current.select(0).and_then(|lowest| {
+            let top = (lowest..N).find(|&i| !current[i])?;
+            let mut next = current;
+            for i in 0..top {
+                next[i] = i + lowest + 1 < top;
+            }
+            next.set(top);
+            Some(next)
+        });
+
+        Some(current)
+    }
+}
+
+/// Returns the binomial coefficient `n choose r`, or `None` on overflow.
+pub(crate) fn binomial(n: usize, r: usize) -> Option<u128> {
+    if r > n {
+        return Some(0);
+    }
+
+    // C(n, i + 1) = C(n, i) (n - i) / (i + 1), dividing by the gcd first
+    // so that the multiplication overflows only if the result does.
+    (0..r.min(n - r)).try_fold(1u128, |c, i| {
+        let divisor = i as u128 + 1;
+        let g = gcd(c, divisor);
+        (c / g).checked_mul((n - i) as u128 / (divisor / g))
+    })
+}
+
+fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+impl<const N: usize> SizedBitset<N> {
+    /// Returns the rank in colexicographic order among the bitsets with the same number of bits set to true,
+    /// based on the combinatorial number system.
+    ///
+    /// Returns an error if the rank overflows `u128`.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::SizedBitset;
+    /// let bitset = SizedBitset::from_const([true, false, true, false]);
+    /// assert_eq!(bitset.rank_combination().unwrap(), 1);
+    /// ```
+    pub fn rank_combination(&self) -> Result<u128, CombinationError> {
+        self.iter()
+            .enumerate()
+            .filter(|(_, bit)| **bit)
+            .enumerate()
+            .try_fold(0u128, |rank, (j, (c, _))| {
+                binomial(c, j + 1)
+                    .and_then(|term| rank.checked_add(term))
+                    .ok_or(CombinationError::Overflow)
+            })
+    }
+
+    /// Returns the bitset with `k` bits set to true whose rank in colexicographic order is `rank`.
+    /// This is the inverse of [SizedBitset::rank_combination].
+    ///
+    /// Returns an error if `rank` is not less than `N choose k`.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::SizedBitset;
+    /// let bitset = SizedBitset::<4>::unrank_combination(2, 1).unwrap();
+    /// assert_eq!(bitset, SizedBitset::from_const([true, false, true, false]));
+    /// assert!(SizedBitset::<4>::unrank_combination(2, 6).is_err());
+    /// ```
+    pub fn unrank_combination(k: usize, mut rank: u128) -> Result<Self, CombinationError> {
+        // the total overflows `u128` only if every `rank` is in range.
+        if let Some(total) = binomial(N, k) {
+            if rank >= total {
+                return Err(CombinationError::OutOfRange { rank, total });
+            }
+        }
+
+        let mut bitset = Self::new();
+        let mut cap = N;
+        for j in (1..=k).rev() {
+            // the largest `c` such that `c choose j <= rank` (overflow means too large)
+            let (c, term) = (j - 1..cap)
+                .rev()
+                .find_map(|c| {
+                    binomial(c, j)
+                        .filter(|term| *term <= rank)
+                        .map(|term| (c, term))
+                })
+                .unwrap_or((j - 1, 0));
+            bitset.set(c);
+            rank -= term;
+            cap = c;
+        }
+
+        Ok(bitset)
+    }
+
+    /// Returns an iterator over all bitsets with exactly `k` bits set to true, in colexicographic order.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::convert::To8;
+    /// use sized_bitset::SizedBitset;
+    /// let combinations = SizedBitset::<4>::combinations(2)
+    ///     .map(|bitset| bitset.to_u8())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(combinations, vec![0b0011, 0b0101, 0b0110, 0b1001, 0b1010, 0b1100]);
+    /// ```
+    pub fn combinations(k: usize) -> Combinations<N> {
+        Combinations::new(k)
+    }
+}
@@ -11,4 +11,38 @@ pub enum ConversionError {
     },
     #[error("invalid character: {0})")]
     FromStr(String),
+    #[error("too few bits: expected {expected}, but got {actual}")]
+    TooShort { expected: usize, actual: usize },
+    #[error("too many bits: expected {expected}")]
+    TooLong { expected: usize },
+    #[error("wrong number of values: expected {expected}, but got {actual}")]
+    LengthMismatch { expected: usize, actual: usize },
+}
+
+#[derive(Debug, Error)]
+pub enum PermutationError {
+    #[error("index out of range: the size is {size} but the index is {index} (at {position})")]
+    OutOfRange {
+        position: usize,
+        index: usize,
+        size: usize,
+    },
+    #[error("not a bijection: {index} appears more than once")]
+    Duplicated { index: usize },
+    #[error("not a bijection: cannot map {from} bits onto {to} bits")]
+    SizeMismatch { from: usize, to: usize },
+}
+
+#[derive(Debug, Error)]
+pub enum FieldError {
+    #[error("value {value} does not fit in {width} bits")]
+    Overflow { value: u128, width: usize },
+}
+
+#[derive(Debug, Error)]
+pub enum CombinationError {
+    #[error("the rank overflows u128")]
+    Overflow,
+    #[error("rank out of range: the total is {total} but the rank is {rank}")]
+    OutOfRange { rank: u128, total: u128 },
 }
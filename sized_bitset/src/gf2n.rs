@@ -0,0 +1,256 @@
+use crate::SizedBitset;
+
+/// Finite field GF(2^N) with elements represented as [SizedBitset].
+///
+/// The field is defined by an irreducible polynomial `x^N + modulus`,
+/// where `modulus` holds the lower coefficients (the leading `x^N` is implicit).
+/// The `i`-th bit of an element is the coefficient of `x^i`.
+///
+/// # Example
+/// ```
+/// use sized_bitset::{convert::To8, Gf2n, SizedBitset};
+/// let field = Gf2n::<8>::AES;
+/// let a: SizedBitset<8> = 0x57u8.into();
+/// let b: SizedBitset<8> = 0x83u8.into();
+///
+/// assert_eq!(field.mul(a, b).to_u8(), 0xc1);
+/// assert_eq!(field.mul(a, field.inverse(a).unwrap()).to_u8(), 0x01);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Gf2n<const N: usize> {
+    modulus: SizedBitset<N>,
+}
+
+impl Gf2n<8> {
+    /// GF(2^8) used by AES (`x^8 + x^4 + x^3 + x + 1`).
+    pub const AES: Self = Self::new(SizedBitset::<8>::from_u8(0x1b));
+}
+
+impl Gf2n<128> {
+    /// GF(2^128) used by GHASH (`x^128 + x^7 + x^2 + x + 1`).
+    ///
+    /// Note that GHASH serializes field elements bit-reflected: the leftmost bit of a GCM block
+    /// is the coefficient of `x^0`. A block read by `u128::from_be_bytes` is converted by
+    /// [SizedBitset::reverse_bits] in both directions.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::{convert::To128, Gf2n, SizedBitset};
+    /// let field = Gf2n::<128>::GHASH;
+    /// let block = |bytes: u128| SizedBitset::<128>::from(bytes).reverse_bits();
+    ///
+    /// // GHASH(H, {}, C) of the test case 2 by McGrew and Viega
+    /// let h = block(0x66e94bd4ef8a2c3b884cfa59ca342b2e);
+    /// let c = block(0x0388dace60b6a392f328c2b971b2fe78);
+    /// let lengths = block(0x80);
+    /// let tag = field.mul(field.mul(c, h) ^ lengths, h);
+    /// assert_eq!(tag.reverse_bits().to_u128(), 0xf38cbb1ad69223dcc3457ae5b6b0f885);
+    /// ```
+    pub const GHASH: Self = Self::new(SizedBitset::<128>::from_u8(0x87));
+}
+
+impl<const N: usize> Gf2n<N> {
+    /// Defines GF(2^N) by the irreducible polynomial `x^N + modulus`.
+    ///
+    /// Irreducibility is not checked (see [Gf2n::is_irreducible]).
+    pub const fn new(modulus: SizedBitset<N>) -> Self {
+        Self { modulus }
+    }
+
+    /// Returns the lower coefficients of the defining polynomial.
+    pub fn modulus(&self) -> &SizedBitset<N> {
+        &self.modulus
+    }
+
+    /// Returns the additive identity.
+    pub fn zero(&self) -> SizedBitset<N> {
+        SizedBitset::new()
+    }
+
+    /// Returns the multiplicative identity.
+    pub fn one(&self) -> SizedBitset<N> {
+        SizedBitset::from_fn(|i| i == 0)
+    }
+
+    /// Field addition (= subtraction), i.e. bitwise XOR.
+    pub fn add(&self, a: SizedBitset<N>, b: SizedBitset<N>) -> SizedBitset<N> {
+        a ^ b
+    }
+
+    /// Field multiplication.
+    pub fn mul(&self, a: SizedBitset<N>, b: SizedBitset<N>) -> SizedBitset<N> {
+        let mut product = self.zero();
+        for i in (0..N).rev() {
+            if product.shl_in(false) {
+                product ^= self.modulus;
+            }
+            if b[i] {
+                product ^= a;
+            }
+        }
+        product
+    }
+
+    /// Raises `a` to the power of `exp`.
+    pub fn pow(&self, a: SizedBitset<N>, exp: u128) -> SizedBitset<N> {
+        (0..(u128::BITS - exp.leading_zeros()))
+            .rev()
+            .fold(self.one(), |power, i| {
+                let square = self.mul(power, power);
+                if (exp >> i) & 1 == 1 {
+                    self.mul(square, a)
+                } else {
+                    square
+                }
+            })
+    }
+
+    /// Returns the multiplicative inverse of `a`, or `None` if `a` is zero.
+    ///
+    /// Panics if `N > 128`.
+    pub fn inverse(&self, a: SizedBitset<N>) -> Option<SizedBitset<N>> {
+        assert!(
+            N <= 128,
+            "the inverse is supported up to 128 bits, but it is {N}"
+        );
+
+        // a^(2^N - 2) = a^-1 by Fermat's little theorem
+        a.any().then(|| self.pow(a, (u128::MAX >> (128 - N)) - 1))
+    }
+
+    /// Checks if the defining polynomial `x^N + modulus` is irreducible by Rabin's test.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::{Gf2n, SizedBitset};
+    /// assert!(Gf2n::<8>::AES.is_irreducible());
+    /// // x^4 + x^2 + 1 = (x^2 + x + 1)^2
+    /// assert!(!Gf2n::<4>::new(SizedBitset::from_const([true, false, true, false])).is_irreducible());
+    /// ```
+    pub fn is_irreducible(&self) -> bool {
+        // x reduced modulo the polynomial
+        let mut x = self.one();
+        if x.shl_in(false) {
+            x ^= self.modulus;
+        }
+        let frobenius = |k: usize| (0..k).fold(x, |power, _| self.mul(power, power));
+
+        // x^(2^N) = x, and x^(2^(N/p)) - x is coprime to the polynomial for every prime factor p of N
+        frobenius(N) == x
+            && (2..=N)
+                .filter(|&p| N % p == 0 && (2..p).all(|q| p % q != 0))
+                .all(|p| self.is_coprime(frobenius(N / p) ^ x))
+    }
+
+    /// Checks if `a` is coprime to the defining polynomial.
+    fn is_coprime(&self, a: SizedBitset<N>) -> bool {
+        if a.none() {
+            return false;
+        }
+
+        // (x^N + modulus) mod a, computed as (x (x^(N-1) mod a) + modulus) mod a to stay within N bits
+        let (_, high) = SizedBitset::from_fn(|i| i == N - 1).poly_div_rem(&a);
+        let (_, reduced) = ((high << 1) ^ self.modulus).poly_div_rem(&a);
+        a.poly_gcd(&reduced) == self.one()
+    }
+
+    /// Precomputes exp/log tables for faster multiplication and inversion.
+    ///
+    /// Returns `None` if the polynomial is not irreducible.
+    /// Panics if `N` is not in `1..=16`.
+    pub fn tables(&self) -> Option<Gf2nTables<N>> {
+        Gf2nTables::new(self)
+    }
+}
+
+/// Precomputed exp/log tables of GF(2^N).
+///
+/// # Example
+/// ```
+/// use sized_bitset::{convert::To8, Gf2n, SizedBitset};
+/// let field = Gf2n::<8>::AES;
+/// let tables = field.tables().unwrap();
+/// let a: SizedBitset<8> = 0x53u8.into();
+///
+/// assert_eq!(tables.inverse(a).unwrap().to_u8(), 0xca);
+/// assert_eq!(tables.mul(a, a), field.mul(a, a));
+/// assert_eq!(tables.exp(tables.log(a).unwrap()), a);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Gf2nTables<const N: usize> {
+    /// `exp[i]` is `g^i` for a primitive element `g`.
+    exp: Vec<SizedBitset<N>>,
+    /// `log[a]` is `i` such that `g^i = a` (`log[0]` is unused).
+    log: Vec<usize>,
+}
+
+impl<const N: usize> Gf2nTables<N> {
+    /// Builds the tables of `field` by searching for a primitive element.
+    ///
+    /// Returns `None` if the polynomial is not irreducible.
+    /// Panics if `N` is not in `1..=16`.
+    pub fn new(field: &Gf2n<N>) -> Option<Self> {
+        assert!(
+            (1..=16).contains(&N),
+            "the tables are supported for 1..=16 bits, but it is {N}"
+        );
+
+        if !field.is_irreducible() {
+            return None;
+        }
+
+        let order = (1usize << N) - 1;
+
+        (1..=order).find_map(|candidate| {
+            let generator = SizedBitset::from_fn(|i| (candidate >> i) & 1 == 1);
+            let mut exp = Vec::with_capacity(order);
+            let mut log = vec![0; order + 1];
+            let mut power = field.one();
+
+            for i in 0..order {
+                // the powers cycle before `order`, so `generator` is not primitive.
+                if i > 0 && power == field.one() {
+                    return None;
+                }
+                exp.push(power);
+                log[power.get_bits(..) as usize] = i;
+                power = field.mul(power, generator);
+            }
+
+            (power == field.one()).then_some(Self { exp, log })
+        })
+    }
+
+    /// Returns `g^i` for the primitive element `g`.
+    pub fn exp(&self, i: usize) -> SizedBitset<N> {
+        self.exp[i % self.exp.len()]
+    }
+
+    /// Returns the discrete logarithm of `a` to the base of the primitive element, or `None` if `a` is zero.
+    pub fn log(&self, a: SizedBitset<N>) -> Option<usize> {
+        a.any().then(|| self.log[a.get_bits(..) as usize])
+    }
+
+    /// Field multiplication by table lookups.
+    pub fn mul(&self, a: SizedBitset<N>, b: SizedBitset<N>) -> SizedBitset<N> {
+        match (self.log(a), self.log(b)) {
+            (Some(a), Some(b)) => self.exp(a + b),
+            _ => SizedBitset::new(),
+        }
+    }
+
+    /// Returns the multiplicative inverse of `a` by table lookups, or `None` if `a` is zero.
+    pub fn inverse(&self, a: SizedBitset<N>) -> Option<SizedBitset<N>> {
+        self.log(a).map(|i| self.exp(self.exp.len() - i))
+    }
+
+    /// Raises `a` to the power of `exp` by table lookups.
+    pub fn pow(&self, a: SizedBitset<N>, exp: u128) -> SizedBitset<N> {
+        let order = self.exp.len() as u128;
+        match self.log(a) {
+            _ if exp == 0 => self.exp(0),
+            Some(i) => self.exp((i as u128 * (exp % order) % order) as usize),
+            None => SizedBitset::new(),
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use crate::SizedBitset;
+
+/// An iterator over all `2^N` bitsets in Gray code order.
+///
+/// This struct is created by [SizedBitset::gray_codes].
+#[derive(Debug, Clone)]
+pub struct GrayCodes<const N: usize> {
+    next: Option<SizedBitset<N>>,
+    /// Whether `next` has an odd number of bits set to true.
+    odd: bool,
+}
+
+impl<const N: usize> GrayCodes<N> {
+    /// Returns an iterator starting from the bitset that all bits is false.
+    pub fn new() -> Self {
+        Self {
+            next: Some(SizedBitset::new()),
+            odd: false,
+        }
+    }
+}
+
+impl<const N: usize> Default for GrayCodes<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Iterator for GrayCodes<N> {
+    type Item = SizedBitset<N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next?;
+
+        // flip the lowest bit for even parity,
+        // otherwise the bit next to the lowest bit set to true.
+        let flip = if self.odd {
+            current.select(0).map(|lowest| lowest + 1)
+        } else {
+            Some(0)
+        };
+
+        self.next = flip.filter(|&i| i < N).map(|i| {
+            let mut next = current;
+            next[i] = !next[i];
+            next
+        });
+        self.odd = !self.odd;
+
+        Some(current)
+    }
+}
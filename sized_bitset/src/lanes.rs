@@ -0,0 +1,161 @@
+use crate::{error::FieldError, SizedBitset};
+
+/// [SizedBitset] viewed as `N / K` unsigned lanes of `K` bits (SIMD within a register).
+///
+/// The `i`-th lane consists of the bits `i * K..(i + 1) * K`, whose lowest bit is `i * K`.
+///
+/// # Example
+/// ```
+/// use sized_bitset::{Lanes, SizedBitset};
+/// let mut counters = SizedBitset::<12>::new().lanes::<3>();
+/// counters.set_lane(0, 0b111).unwrap();
+/// counters.set_lane(2, 0b010).unwrap();
+///
+/// let ones = Lanes::<12, 3>::splat(1);
+/// let counters = counters.wrapping_add(&ones);
+/// assert_eq!(counters.iter().collect::<Vec<_>>(), vec![0, 1, 3, 1]);
+/// assert_eq!(counters.sum(), 5);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Lanes<const N: usize, const K: usize> {
+    bitset: SizedBitset<N>,
+}
+
+impl<const N: usize, const K: usize> Lanes<N, K> {
+    /// Views `bitset` as lanes of `K` bits.
+    ///
+    /// Panics if `K` is not in `1..=128` or `N` is not a multiple of `K`.
+    pub fn new(bitset: SizedBitset<N>) -> Self {
+        Self::assert_width();
+
+        Self { bitset }
+    }
+
+    /// Returns lanes that all hold `value` truncated to `K` bits.
+    ///
+    /// Panics if `K` is not in `1..=128` or `N` is not a multiple of `K`.
+    pub fn splat(value: u128) -> Self {
+        Self::assert_width();
+
+        let value = Self::truncate(value);
+        Self {
+            bitset: SizedBitset::from_fn(|i| (value >> (i % K)) & 1 == 1),
+        }
+    }
+
+    fn assert_width() {
+        assert!(
+            (1..=128).contains(&K) && N % K == 0,
+            "the lane width must be in 1..=128 and divide the size {N}, but it is {K}"
+        );
+    }
+
+    /// Returns the underlying [SizedBitset].
+    pub fn bitset(&self) -> &SizedBitset<N> {
+        &self.bitset
+    }
+
+    /// Returns the underlying [SizedBitset].
+    pub fn into_bitset(self) -> SizedBitset<N> {
+        self.bitset
+    }
+
+    /// Returns the number of lanes (= N / K).
+    pub fn len(&self) -> usize {
+        N / K
+    }
+
+    /// Checks if there is no lane.
+    pub fn is_empty(&self) -> bool {
+        N == 0
+    }
+
+    /// Returns the value of the `i`-th lane.
+    ///
+    /// Panics if `i` is out of range.
+    pub fn lane(&self, i: usize) -> u128 {
+        assert!(i < self.len(), "lane index out of range: {i}");
+        self.bitset.get_bits(i * K..(i + 1) * K)
+    }
+
+    /// Sets the value of the `i`-th lane.
+    ///
+    /// Returns an error if `value` does not fit in `K` bits.
+    /// Panics if `i` is out of range.
+    pub fn set_lane(&mut self, i: usize, value: u128) -> Result<(), FieldError> {
+        assert!(i < self.len(), "lane index out of range: {i}");
+        self.bitset.set_bits(i * K..(i + 1) * K, value)
+    }
+
+    /// Returns an iterator over the lane values, from the lowest lane.
+    pub fn iter(&self) -> impl Iterator<Item = u128> + '_ {
+        (0..self.len()).map(|i| self.lane(i))
+    }
+
+    /// Lane-wise wrapping addition.
+    pub fn wrapping_add(&self, rhs: &Self) -> Self {
+        self.zip_lanes(rhs, u128::wrapping_add)
+    }
+
+    /// Lane-wise wrapping subtraction.
+    pub fn wrapping_sub(&self, rhs: &Self) -> Self {
+        self.zip_lanes(rhs, u128::wrapping_sub)
+    }
+
+    /// Lane-wise `==`.
+    /// Returns [SizedBitset] whose lanes are all ones where the comparison holds and all zeros otherwise.
+    pub fn eq_mask(&self, rhs: &Self) -> SizedBitset<N> {
+        self.compare(rhs, |a, b| a == b)
+    }
+
+    /// Lane-wise unsigned `<`.
+    /// Returns [SizedBitset] whose lanes are all ones where the comparison holds and all zeros otherwise.
+    pub fn lt_mask(&self, rhs: &Self) -> SizedBitset<N> {
+        self.compare(rhs, |a, b| a < b)
+    }
+
+    /// Lane-wise unsigned `>`.
+    /// Returns [SizedBitset] whose lanes are all ones where the comparison holds and all zeros otherwise.
+    pub fn gt_mask(&self, rhs: &Self) -> SizedBitset<N> {
+        self.compare(rhs, |a, b| a > b)
+    }
+
+    /// Returns the sum of all lanes, wrapping around on overflow.
+    pub fn sum(&self) -> u128 {
+        self.iter().fold(0, u128::wrapping_add)
+    }
+
+    fn truncate(value: u128) -> u128 {
+        value & (u128::MAX >> (128 - K))
+    }
+
+    /// Writes each result lane in place, without collecting the lanes.
+    fn zip_lanes(&self, rhs: &Self, f: impl Fn(u128, u128) -> u128) -> Self {
+        let mut bitset = self.bitset;
+        for i in 0..self.len() {
+            bitset
+                .set_bits(
+                    i * K..(i + 1) * K,
+                    Self::truncate(f(self.lane(i), rhs.lane(i))),
+                )
+                .expect("the lane is truncated to K bits");
+        }
+        Self { bitset }
+    }
+
+    fn compare(&self, rhs: &Self, f: impl Fn(u128, u128) -> bool) -> SizedBitset<N> {
+        let mut mask = SizedBitset::from_const([false; N]);
+        for i in (0..self.len()).filter(|&i| f(self.lane(i), rhs.lane(i))) {
+            for bit in i * K..(i + 1) * K {
+                mask.set(bit);
+            }
+        }
+        mask
+    }
+}
+
+impl<const N: usize, const K: usize> From<SizedBitset<N>> for Lanes<N, K> {
+    fn from(bitset: SizedBitset<N>) -> Self {
+        Self::new(bitset)
+    }
+}
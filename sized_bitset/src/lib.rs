@@ -66,8 +66,49 @@
 //! }
 //! ```
 //!
+//! ## Morton Order
+//!
+//! `SizedBitset::<{N}>::interleave` and `SizedBitset::<{2N}>::deinterleave` are defined if and only if `2N <= 128`.
+//!
+//! `SizedBitset::<{N}>::interleave3` and `SizedBitset::<{3N}>::deinterleave3` are defined if and only if `3N <= 128`.
+//!
+//! ```
+//! use sized_bitset::{convert::*, SizedBitset};
+//! let x: SizedBitset<4> = SizedBitset::from_const([true, false, true, true]);
+//! let y: SizedBitset<4> = SizedBitset::from_const([false, false, true, false]);
+//!
+//! let key = SizedBitset::<4>::interleave(x, y);
+//! assert_eq!(key.to_u8(), 0b01_11_00_01);
+//! assert_eq!(key.deinterleave(), (x, y));
+//!
+//! let z: SizedBitset<4> = SizedBitset::from_const([true, true, true, false]);
+//! let key = SizedBitset::<4>::interleave3(x, y, z);
+//! assert_eq!(key.to_u16(), 0b001_111_100_101);
+//! assert_eq!(key.deinterleave3(), (x, y, z));
+//! ```
+//!
 
+mod aggregate;
+mod arithmetic;
 mod bitset;
+mod combinations;
 pub mod error;
+mod gf2n;
+mod gray;
+mod lanes;
+mod permutation;
+mod rank_select;
+mod submasks;
+mod subset_table;
+mod values;
 
 pub use bitset::*;
+pub use combinations::*;
+pub use gf2n::*;
+pub use gray::*;
+pub use lanes::*;
+pub use permutation::*;
+pub use rank_select::*;
+pub use submasks::*;
+pub use subset_table::*;
+pub use values::*;
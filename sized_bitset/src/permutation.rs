@@ -0,0 +1,90 @@
+use crate::{error::PermutationError, SizedBitset};
+
+/// Returns the inverse of the bijective permutation table `table`.
+///
+/// # Example
+/// ```
+/// use sized_bitset::inverse_permutation;
+/// assert_eq!(inverse_permutation(&[2, 0, 3, 1]).unwrap(), [1, 3, 0, 2]);
+/// assert!(inverse_permutation(&[2, 0, 2, 1]).is_err());
+/// ```
+pub fn inverse_permutation<const N: usize>(
+    table: &[usize; N],
+) -> Result<[usize; N], PermutationError> {
+    let mut inverse = [None; N];
+
+    for (position, &index) in table.iter().enumerate() {
+        match inverse.get_mut(index) {
+            None => {
+                return Err(PermutationError::OutOfRange {
+                    position,
+                    index,
+                    size: N,
+                })
+            }
+            Some(Some(_)) => return Err(PermutationError::Duplicated { index }),
+            Some(slot) => *slot = Some(position),
+        }
+    }
+
+    // every slot is filled because `table` has `N` distinct indices less than `N`.
+    Ok(inverse.map(|position| position.unwrap_or_default()))
+}
+
+/// Precompiled bit permutation from `N` bits to `M` bits.
+///
+/// The `j`-th output bit comes from the `table[j]`-th input bit.
+/// The table is validated once on construction.
+///
+/// # Example
+/// ```
+/// use sized_bitset::{BitPermutation, SizedBitset};
+/// let permutation = BitPermutation::<4, 4>::new([2, 0, 3, 1]).unwrap();
+/// let bitset = SizedBitset::from_const([true, true, false, false]);
+///
+/// let permuted = permutation.apply(&bitset);
+/// assert_eq!(permuted, SizedBitset::from_const([false, true, false, true]));
+/// assert_eq!(permutation.inverse().unwrap().apply(&permuted), bitset);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BitPermutation<const N: usize, const M: usize> {
+    table: [usize; M],
+}
+
+impl<const N: usize, const M: usize> BitPermutation<N, M> {
+    /// Validates that every index in `table` is less than `N`.
+    pub fn new(table: [usize; M]) -> Result<Self, PermutationError> {
+        match table.iter().enumerate().find(|(_, &index)| index >= N) {
+            Some((position, &index)) => Err(PermutationError::OutOfRange {
+                position,
+                index,
+                size: N,
+            }),
+            None => Ok(Self { table }),
+        }
+    }
+
+    /// Returns the permutation table.
+    pub fn table(&self) -> &[usize; M] {
+        &self.table
+    }
+
+    /// Applies the permutation to `bitset`.
+    pub fn apply(&self, bitset: &SizedBitset<N>) -> SizedBitset<M> {
+        bitset.permute(&self.table)
+    }
+
+    /// Returns the inverse permutation.
+    ///
+    /// Returns an error if the permutation is not a bijection.
+    pub fn inverse(&self) -> Result<BitPermutation<M, N>, PermutationError> {
+        if N != M {
+            return Err(PermutationError::SizeMismatch { from: N, to: M });
+        }
+
+        let inverse = inverse_permutation(&self.table)?;
+        Ok(BitPermutation {
+            table: core::array::from_fn(|i| inverse[i]),
+        })
+    }
+}
@@ -0,0 +1,87 @@
+use crate::SizedBitset;
+
+/// The number of bits covered by one directory entry.
+const BLOCK_SIZE: usize = 64;
+
+/// [SizedBitset] with a precomputed directory of per-block counts.
+///
+/// `rank` runs in constant time (one directory lookup plus a scan of at most one block)
+/// and `select` in logarithmic time (a binary search over the directory).
+///
+/// # Example
+/// ```
+/// use sized_bitset::{RankSelect, SizedBitset};
+/// let bitset: SizedBitset<128> = (u64::MAX as u128).into();
+/// let directory = RankSelect::new(bitset);
+///
+/// assert_eq!(directory.rank(100), 64);
+/// assert_eq!(directory.select(63), Some(63));
+/// assert_eq!(directory.select(64), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct RankSelect<const N: usize> {
+    bitset: SizedBitset<N>,
+    /// `blocks[b]` is the number of bits set to true below the index `b * BLOCK_SIZE`.
+    /// The last entry holds the total count.
+    blocks: Vec<usize>,
+}
+
+impl<const N: usize> RankSelect<N> {
+    /// Builds the directory for `bitset`.
+    pub fn new(bitset: SizedBitset<N>) -> Self {
+        let blocks = std::iter::once(0)
+            .chain((0..N).step_by(BLOCK_SIZE).scan(0, |sum, start| {
+                *sum += (start..N.min(start + BLOCK_SIZE))
+                    .filter(|&i| bitset[i])
+                    .count();
+                Some(*sum)
+            }))
+            .collect();
+
+        Self { bitset, blocks }
+    }
+
+    /// Returns the underlying [SizedBitset].
+    pub fn bitset(&self) -> &SizedBitset<N> {
+        &self.bitset
+    }
+
+    /// Returns the number of bits set to true.
+    pub fn count(&self) -> usize {
+        self.blocks[self.blocks.len() - 1]
+    }
+
+    /// Returns the number of bits set to true below the index `i`.
+    ///
+    /// Panics if `i > N`.
+    pub fn rank(&self, i: usize) -> usize {
+        assert!(
+            i <= N,
+            "index out of range: the size is {N} but the index is {i}"
+        );
+
+        let block = i / BLOCK_SIZE;
+        self.blocks[block] + (block * BLOCK_SIZE..i).filter(|&j| self.bitset[j]).count()
+    }
+
+    /// Returns the index of the `k`-th (0-origin) bit set to true.
+    ///
+    /// Returns `None` if fewer than `k + 1` bits are set.
+    pub fn select(&self, k: usize) -> Option<usize> {
+        if k >= self.count() {
+            return None;
+        }
+
+        // the last block whose preceding count is at most `k`
+        let block = self.blocks.partition_point(|&count| count <= k) - 1;
+        (block * BLOCK_SIZE..N)
+            .filter(|&i| self.bitset[i])
+            .nth(k - self.blocks[block])
+    }
+}
+
+impl<const N: usize> From<SizedBitset<N>> for RankSelect<N> {
+    fn from(bitset: SizedBitset<N>) -> Self {
+        Self::new(bitset)
+    }
+}
@@ -0,0 +1,137 @@
+use crate::SizedBitset;
+
+/// An iterator over all subsets of a [SizedBitset], in descending numeric order.
+///
+/// This struct is created by [SizedBitset::submasks] and [SizedBitset::proper_submasks].
+#[derive(Debug, Clone)]
+pub struct Submasks<const N: usize> {
+    mask: SizedBitset<N>,
+    next: Option<SizedBitset<N>>,
+}
+
+impl<const N: usize> Submasks<N> {
+    /// Returns an iterator starting from `mask` itself.
+    pub fn new(mask: SizedBitset<N>) -> Self {
+        Self {
+            mask,
+            next: Some(mask),
+        }
+    }
+}
+
+impl<const N: usize> Iterator for Submasks<N> {
+    type Item = SizedBitset<N>;
+
+    /// `s = (s - 1) & mask`: clears the lowest bit of `s` and sets every bit of `mask` below it.
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next?;
+
+        self.next = current.select(0).map(|lowest| {
+            let mut next = current;
+            next.reset(lowest);
+            for i in 0..lowest {
+                next[i] = self.mask[i];
+            }
+            next
+        });
+
+        Some(current)
+    }
+}
+
+/// An iterator over all supersets of a [SizedBitset] within a universe, in ascending numeric order.
+///
+/// This struct is created by [SizedBitset::supermasks_within].
+#[derive(Debug, Clone)]
+pub struct Supermasks<const N: usize> {
+    base: SizedBitset<N>,
+    universe: SizedBitset<N>,
+    next: Option<SizedBitset<N>>,
+}
+
+impl<const N: usize> Supermasks<N> {
+    /// Returns an iterator starting from `base` itself.
+    /// Bits of `base` outside `universe` are kept in every superset.
+    pub fn new(base: SizedBitset<N>, universe: SizedBitset<N>) -> Self {
+        Self {
+            base,
+            universe: universe | base,
+            next: Some(base),
+        }
+    }
+}
+
+impl<const N: usize> Iterator for Supermasks<N> {
+    type Item = SizedBitset<N>;
+
+    /// `s = (s + 1) | base` within `universe`: sets the lowest free bit of `s`
+    /// and clears every free bit below it.
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next?;
+
+        self.next = (0..N)
+            .find(|&i| self.universe[i] && !current[i])
+            .map(|lowest| {
+                let mut next = current;
+                next.set(lowest);
+                for i in 0..lowest {
+                    next[i] = self.base[i];
+                }
+                next
+            });
+
+        Some(current)
+    }
+}
+
+impl<const N: usize> SizedBitset<N> {
+    /// Returns an iterator over all subsets of the bits set to true, from `self` down to the empty set.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::convert::To8;
+    /// use sized_bitset::SizedBitset;
+    /// let submasks = SizedBitset::<4>::from_const([true, false, true, true])
+    ///     .submasks()
+    ///     .map(|bitset| bitset.to_u8())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(submasks, vec![0b1101, 0b1100, 0b1001, 0b1000, 0b0101, 0b0100, 0b0001, 0b0000]);
+    /// ```
+    pub fn submasks(&self) -> Submasks<N> {
+        Submasks::new(*self)
+    }
+
+    /// Returns an iterator over all proper subsets of the bits set to true, in descending numeric order.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::convert::To8;
+    /// use sized_bitset::SizedBitset;
+    /// let submasks = SizedBitset::<4>::from_const([true, true, false, false])
+    ///     .proper_submasks()
+    ///     .map(|bitset| bitset.to_u8())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(submasks, vec![0b0010, 0b0001, 0b0000]);
+    /// ```
+    pub fn proper_submasks(&self) -> std::iter::Skip<Submasks<N>> {
+        self.submasks().skip(1)
+    }
+
+    /// Returns an iterator over all supersets of `self` whose extra bits lie in `universe`,
+    /// from `self` up to `self | universe`.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::convert::To8;
+    /// use sized_bitset::SizedBitset;
+    /// let universe = SizedBitset::<4>::from_const([true, true, false, true]);
+    /// let supermasks = SizedBitset::<4>::from_const([false, true, false, false])
+    ///     .supermasks_within(&universe)
+    ///     .map(|bitset| bitset.to_u8())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(supermasks, vec![0b0010, 0b0011, 0b1010, 0b1011]);
+    /// ```
+    pub fn supermasks_within(&self, universe: &Self) -> Supermasks<N> {
+        Supermasks::new(*self, *universe)
+    }
+}
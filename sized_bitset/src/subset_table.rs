@@ -0,0 +1,195 @@
+use std::ops::{AddAssign, Index, IndexMut, SubAssign};
+
+use crate::{error::ConversionError, SizedBitset};
+
+/// A table of `2^N` values keyed by [SizedBitset]`<N>`, where the bit `i` of a key weighs `2^i` in the layout.
+///
+/// Supports the sum over subsets (zeta) and Möbius transforms used in bitmask dynamic programming.
+///
+/// # Example
+/// ```
+/// use sized_bitset::{SizedBitset, SubsetTable};
+/// let mut table = SubsetTable::<3, i64>::new(1);
+/// table.subset_zeta();
+///
+/// let key = SizedBitset::from_const([true, false, true]);
+/// assert_eq!(table[key], 4);
+///
+/// table.subset_mobius();
+/// assert_eq!(table[key], 1);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SubsetTable<const N: usize, T> {
+    values: Vec<T>,
+}
+
+impl<const N: usize, T> SubsetTable<N, T> {
+    /// Returns the table filled with `value`.
+    ///
+    /// Panics if `2^N` does not fit in `usize`.
+    pub fn new(value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            values: vec![value; Self::size()],
+        }
+    }
+
+    /// Returns the table whose value at each key is `f(key)`.
+    ///
+    /// Panics if `2^N` does not fit in `usize`.
+    pub fn from_fn(f: impl FnMut(SizedBitset<N>) -> T) -> Self {
+        Self {
+            values: (0..Self::size()).map(Self::key).map(f).collect(),
+        }
+    }
+
+    fn size() -> usize {
+        assert!(
+            N < usize::BITS as usize,
+            "the table of size 2^{N} does not fit in usize"
+        );
+
+        1 << N
+    }
+
+    fn key(index: usize) -> SizedBitset<N> {
+        SizedBitset::from_fn(|i| (index >> i) & 1 == 1)
+    }
+
+    fn index(key: &SizedBitset<N>) -> usize {
+        key.get_bits(..) as usize
+    }
+
+    /// Returns the number of entries (= 2^N).
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Always returns `false`, since the table holds at least the empty key.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Returns the values in the order of keys.
+    pub fn as_slice(&self) -> &[T] {
+        &self.values
+    }
+
+    /// Returns the values in the order of keys.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.values
+    }
+
+    /// Returns the values in the order of keys.
+    pub fn into_vec(self) -> Vec<T> {
+        self.values
+    }
+
+    /// Returns an iterator over the entries as `(key, value)` in the order of keys.
+    pub fn iter(&self) -> impl Iterator<Item = (SizedBitset<N>, &T)> + '_ {
+        self.values
+            .iter()
+            .enumerate()
+            .map(|(index, value)| (Self::key(index), value))
+    }
+
+    /// Applies `f(upper, lower)` to every pair of entries whose keys differ only in one bit,
+    /// where `upper` has the bit set, one bit at a time from the lowest.
+    fn butterfly(&mut self, mut f: impl FnMut(&mut T, &mut T)) {
+        for i in 0..N {
+            for block in self.values.chunks_mut(2 << i) {
+                let (lower, upper) = block.split_at_mut(1 << i);
+                for (upper, lower) in upper.iter_mut().zip(lower) {
+                    f(upper, lower);
+                }
+            }
+        }
+    }
+}
+
+impl<const N: usize, T: AddAssign + SubAssign + Clone> SubsetTable<N, T> {
+    /// Replaces each value with the sum over the subsets of its key, in `O(N 2^N)`.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::SubsetTable;
+    /// let mut table = SubsetTable::<2, u32>::from_fn(|key| key.get_bits(..) as u32);
+    /// table.subset_zeta();
+    /// assert_eq!(table.as_slice(), &[0, 1, 2, 6]);
+    /// ```
+    pub fn subset_zeta(&mut self) {
+        self.butterfly(|upper, lower| *upper += lower.clone());
+    }
+
+    /// Inverts [SubsetTable::subset_zeta], in `O(N 2^N)`.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::SubsetTable;
+    /// let mut table = SubsetTable::<2, i32>::from_fn(|key| key.get_bits(..) as i32);
+    /// table.subset_mobius();
+    /// assert_eq!(table.as_slice(), &[0, 1, 2, 0]);
+    /// ```
+    pub fn subset_mobius(&mut self) {
+        self.butterfly(|upper, lower| *upper -= lower.clone());
+    }
+
+    /// Replaces each value with the sum over the supersets of its key, in `O(N 2^N)`.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::SubsetTable;
+    /// let mut table = SubsetTable::<2, u32>::from_fn(|key| key.get_bits(..) as u32);
+    /// table.superset_zeta();
+    /// assert_eq!(table.as_slice(), &[6, 4, 5, 3]);
+    /// ```
+    pub fn superset_zeta(&mut self) {
+        self.butterfly(|upper, lower| *lower += upper.clone());
+    }
+
+    /// Inverts [SubsetTable::superset_zeta], in `O(N 2^N)`.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::SubsetTable;
+    /// let mut table = SubsetTable::<2, i32>::from_fn(|key| key.get_bits(..) as i32);
+    /// table.superset_mobius();
+    /// assert_eq!(table.as_slice(), &[0, -2, -1, 3]);
+    /// ```
+    pub fn superset_mobius(&mut self) {
+        self.butterfly(|upper, lower| *lower -= upper.clone());
+    }
+}
+
+impl<const N: usize, T> Index<SizedBitset<N>> for SubsetTable<N, T> {
+    type Output = T;
+
+    fn index(&self, key: SizedBitset<N>) -> &Self::Output {
+        &self.values[Self::index(&key)]
+    }
+}
+
+impl<const N: usize, T> IndexMut<SizedBitset<N>> for SubsetTable<N, T> {
+    fn index_mut(&mut self, key: SizedBitset<N>) -> &mut Self::Output {
+        &mut self.values[Self::index(&key)]
+    }
+}
+
+impl<const N: usize, T> TryFrom<Vec<T>> for SubsetTable<N, T> {
+    type Error = ConversionError;
+
+    /// Returns an error if the length of `values` is not `2^N`.
+    fn try_from(values: Vec<T>) -> Result<Self, Self::Error> {
+        let expected = Self::size();
+        if values.len() != expected {
+            return Err(ConversionError::LengthMismatch {
+                expected,
+                actual: values.len(),
+            });
+        }
+
+        Ok(Self { values })
+    }
+}
@@ -0,0 +1,127 @@
+use std::ops::{Bound, RangeBounds};
+
+use crate::SizedBitset;
+
+/// An iterator over [SizedBitset]s in ascending numeric order, where the bit `i` weighs `2^i`.
+///
+/// This struct is created by [SizedBitset::all_values] and [SizedBitset::values_in].
+///
+/// The number of remaining values may exceed `usize::MAX` for `N = 64`, so it is given by [Values::len] as `u128`
+/// rather than by `ExactSizeIterator`.
+#[derive(Debug, Clone)]
+pub struct Values<const N: usize> {
+    /// The next value from the front.
+    front: u128,
+    /// One past the next value from the back.
+    back: u128,
+}
+
+impl<const N: usize> Values<N> {
+    /// Returns an iterator over the values in `range`.
+    ///
+    /// Panics if `N > 64`.
+    pub fn new(range: impl RangeBounds<SizedBitset<N>>) -> Self {
+        assert!(N <= 64, "the size must be at most 64, but it is {N}");
+
+        let front = match range.start_bound() {
+            Bound::Included(start) => start.get_bits(..),
+            Bound::Excluded(start) => start.get_bits(..) + 1,
+            Bound::Unbounded => 0,
+        };
+        let back = match range.end_bound() {
+            Bound::Included(end) => end.get_bits(..) + 1,
+            Bound::Excluded(end) => end.get_bits(..),
+            Bound::Unbounded => 1 << N,
+        };
+
+        Self {
+            front,
+            back: back.max(front),
+        }
+    }
+
+    /// Returns the number of remaining values.
+    pub fn len(&self) -> u128 {
+        self.back - self.front
+    }
+
+    /// Checks if no value remains.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn value(value: u128) -> SizedBitset<N> {
+        SizedBitset::from_fn(|i| (value >> i) & 1 == 1)
+    }
+}
+
+impl<const N: usize> Iterator for Values<N> {
+    type Item = SizedBitset<N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        (self.front < self.back).then(|| {
+            self.front += 1;
+            Self::value(self.front - 1)
+        })
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.front = self.back.min(self.front + n as u128);
+        self.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match usize::try_from(self.len()) {
+            Ok(len) => (len, Some(len)),
+            Err(_) => (usize::MAX, None),
+        }
+    }
+}
+
+impl<const N: usize> DoubleEndedIterator for Values<N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        (self.front < self.back).then(|| {
+            self.back -= 1;
+            Self::value(self.back)
+        })
+    }
+}
+
+impl<const N: usize> std::iter::FusedIterator for Values<N> {}
+
+impl<const N: usize> SizedBitset<N> {
+    /// Returns an iterator over all `2^N` bitsets in ascending numeric order.
+    ///
+    /// Panics if `N > 64`.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::convert::To8;
+    /// use sized_bitset::SizedBitset;
+    /// let values = SizedBitset::<3>::all_values();
+    /// assert_eq!(values.len(), 8);
+    /// assert_eq!(values.rev().map(|bitset| bitset.to_u8()).collect::<Vec<_>>(), vec![7, 6, 5, 4, 3, 2, 1, 0]);
+    /// ```
+    pub fn all_values() -> Values<N> {
+        Values::new(..)
+    }
+
+    /// Returns an iterator over the bitsets in `range` in ascending numeric order.
+    ///
+    /// Panics if `N > 64`.
+    ///
+    /// # Example
+    /// ```
+    /// use sized_bitset::convert::To8;
+    /// use sized_bitset::SizedBitset;
+    /// let lo = SizedBitset::<4>::from_const([true, true, false, false]);
+    /// let hi = SizedBitset::<4>::from_const([false, false, true, false]);
+    /// let values = SizedBitset::values_in(lo..=hi)
+    ///     .map(|bitset| bitset.to_u8())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(values, vec![3, 4]);
+    /// ```
+    pub fn values_in(range: impl RangeBounds<Self>) -> Values<N> {
+        Values::new(range)
+    }
+}
@@ -325,3 +325,64 @@ pub fn generate_num_traits(_: TokenStream) -> TokenStream {
         .collect::<proc_macro2::TokenStream>()
         .into()
 }
+
+#[proc_macro]
+pub fn generate_interleave(_: TokenStream) -> TokenStream {
+    let interleave2 = (1usize..=64usize).map(|size| -> proc_macro2::TokenStream {
+        let index = syn::Index::from(size);
+        let double = syn::Index::from(size * 2);
+        quote! {
+            impl SizedBitset<#index> {
+                /// Interleaves the bits of `a` and `b` into Morton (Z-order), starting from `a`.
+                pub fn interleave(a: Self, b: Self) -> SizedBitset<#double> {
+                    SizedBitset::from_fn(|i| [a, b][i % 2].bits[i / 2])
+                }
+            }
+
+            impl SizedBitset<#double> {
+                /// Splits Morton (Z-order) bits into the even and odd bits.
+                pub fn deinterleave(&self) -> (SizedBitset<#index>, SizedBitset<#index>) {
+                    (
+                        SizedBitset::from_fn(|i| self.bits[2 * i]),
+                        SizedBitset::from_fn(|i| self.bits[2 * i + 1]),
+                    )
+                }
+            }
+        }
+    });
+
+    let interleave3 = (1usize..=42usize).map(|size| -> proc_macro2::TokenStream {
+        let index = syn::Index::from(size);
+        let triple = syn::Index::from(size * 3);
+        quote! {
+            impl SizedBitset<#index> {
+                /// Interleaves the bits of `a`, `b` and `c` into Morton (Z-order), starting from `a`.
+                pub fn interleave3(a: Self, b: Self, c: Self) -> SizedBitset<#triple> {
+                    SizedBitset::from_fn(|i| [a, b, c][i % 3].bits[i / 3])
+                }
+            }
+
+            impl SizedBitset<#triple> {
+                /// Splits 3-way Morton (Z-order) bits into every third bit.
+                pub fn deinterleave3(
+                    &self,
+                ) -> (
+                    SizedBitset<#index>,
+                    SizedBitset<#index>,
+                    SizedBitset<#index>,
+                ) {
+                    (
+                        SizedBitset::from_fn(|i| self.bits[3 * i]),
+                        SizedBitset::from_fn(|i| self.bits[3 * i + 1]),
+                        SizedBitset::from_fn(|i| self.bits[3 * i + 2]),
+                    )
+                }
+            }
+        }
+    });
+
+    interleave2
+        .chain(interleave3)
+        .collect::<proc_macro2::TokenStream>()
+        .into()
+}
@@ -1,10 +1,16 @@
 #[cfg(test)]
 mod test {
-    use std::ops::{BitAnd, BitOr, BitXor, Shl, Shr};
+    use std::ops::{BitAnd, BitOr, BitXor, ControlFlow, Shl, Shr};
 
     use coverage_helper::test;
-    use proptest::{prop_assert, prop_assert_eq, prop_assert_ne, proptest};
-    use sized_bitset::{convert::*, SizedBitset};
+    use proptest::{
+        prelude::Just, prop_assert, prop_assert_eq, prop_assert_ne, proptest, strategy::Strategy,
+    };
+    use sized_bitset::{
+        convert::*,
+        error::{CombinationError, ConversionError, PermutationError},
+        inverse_permutation, BitPermutation, Gf2n, Lanes, RankSelect, SizedBitset, SubsetTable,
+    };
 
     #[test]
     fn from_const() {
@@ -16,6 +22,44 @@ mod test {
         let _ = SizedBitset::from_const([true, true, true, true, true, true, true, true]);
     }
 
+    #[test]
+    fn const_masks() {
+        const LOW: SizedBitset<256> = SizedBitset::mask_low(100);
+        const HIGH: SizedBitset<256> = SizedBitset::mask_high(100);
+        const RANGE: SizedBitset<256> = SizedBitset::mask_range(10, 200);
+        const SINGLE: SizedBitset<256> = SizedBitset::single(255);
+        const ALTERNATING: SizedBitset<256> = SizedBitset::alternating();
+        for i in 0..256 {
+            assert_eq!(LOW[i], i < 100);
+            assert_eq!(HIGH[i], i >= 156);
+            assert_eq!(RANGE[i], (10..200).contains(&i));
+            assert_eq!(SINGLE[i], i == 255);
+            assert_eq!(ALTERNATING[i], i % 2 == 0);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn masks(start in 0usize..=40, end in 0usize..=40) {
+            let result = std::panic::catch_unwind(|| SizedBitset::<32>::mask_range(start, end));
+            prop_assert_eq!(result.is_ok(), start <= end && end <= 32);
+            prop_assert_eq!(std::panic::catch_unwind(|| SizedBitset::<32>::mask_low(start)).is_ok(), start <= 32);
+            prop_assert_eq!(std::panic::catch_unwind(|| SizedBitset::<32>::mask_high(start)).is_ok(), start <= 32);
+            prop_assert_eq!(std::panic::catch_unwind(|| SizedBitset::<32>::single(start)).is_ok(), start < 32);
+
+            if let Ok(mask) = result {
+                prop_assert_eq!(mask, SizedBitset::<32>::mask_low(end) & SizedBitset::<32>::mask_low(start).flipped());
+            }
+            if start <= 32 {
+                prop_assert_eq!(SizedBitset::<32>::mask_low(start).to_u32(), u32::MAX.checked_shr(32 - start as u32).unwrap_or(0));
+                prop_assert_eq!(SizedBitset::<32>::mask_high(start).to_u32(), u32::MAX.checked_shl(32 - start as u32).unwrap_or(0));
+            }
+            if start < 32 {
+                prop_assert_eq!(SizedBitset::<32>::single(start).to_u32(), 1 << start);
+            }
+        }
+    }
+
     #[derive(Copy, Clone)]
     enum Switch {
         On,
@@ -57,6 +101,28 @@ mod test {
         }
     }
 
+    proptest! {
+        #[test]
+        fn from_fn(bits: u8) {
+            let bitset = SizedBitset::<8>::from_fn(|i| (bits >> i) & 1 == 1);
+            prop_assert_eq!(bitset.to_u8(), bits);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn try_from_iter(bits in proptest::collection::vec(proptest::bool::ANY, 0..16)) {
+            let result = SizedBitset::<8>::try_from_iter(bits.iter().copied());
+            let is_expected = match (bits.len(), result) {
+                (len, Err(ConversionError::TooShort { expected: 8, actual })) => len == actual,
+                (8, Ok(bitset)) => bitset == bits.as_slice().try_into().unwrap(),
+                (len, Err(ConversionError::TooLong { expected: 8 })) => len > 8,
+                _ => false,
+            };
+            prop_assert!(is_expected);
+        }
+    }
+
     proptest! {
         #[test]
         fn to_string_with(bits: u8) {
@@ -119,6 +185,321 @@ mod test {
         }
     }
 
+    proptest! {
+        #[test]
+        fn parity(bits: u64) {
+            let bitset: SizedBitset<64> = bits.into();
+            prop_assert_eq!(bitset.parity(), bits.count_ones() % 2 == 1);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn fused_count(lhs: u64, rhs: u64) {
+            let lhs_bitset: SizedBitset<64> = lhs.into();
+            let rhs_bitset: SizedBitset<64> = rhs.into();
+            prop_assert_eq!(lhs_bitset.and_count(&rhs_bitset), (lhs & rhs).count_ones() as usize);
+            prop_assert_eq!(lhs_bitset.or_count(&rhs_bitset), (lhs | rhs).count_ones() as usize);
+            prop_assert_eq!(lhs_bitset.xor_count(&rhs_bitset), (lhs ^ rhs).count_ones() as usize);
+            prop_assert_eq!(lhs_bitset.andnot_count(&rhs_bitset), (lhs & !rhs).count_ones() as usize);
+            prop_assert_eq!(lhs_bitset.hamming_distance(&rhs_bitset), (lhs ^ rhs).count_ones() as usize);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn similarity(lhs: u64, rhs: u64) {
+            let lhs_bitset: SizedBitset<64> = lhs.into();
+            let rhs_bitset: SizedBitset<64> = rhs.into();
+            let and = (lhs & rhs).count_ones() as f64;
+            let or = (lhs | rhs).count_ones() as f64;
+            let total = (lhs.count_ones() + rhs.count_ones()) as f64;
+            if or == 0.0 {
+                prop_assert_eq!(lhs_bitset.jaccard(&rhs_bitset), 1.0);
+                prop_assert_eq!(lhs_bitset.dice(&rhs_bitset), 1.0);
+                prop_assert_eq!(lhs_bitset.tanimoto(&rhs_bitset), 1.0);
+            } else {
+                prop_assert_eq!(lhs_bitset.jaccard(&rhs_bitset), and / or);
+                prop_assert_eq!(lhs_bitset.dice(&rhs_bitset), 2.0 * and / total);
+                prop_assert_eq!(lhs_bitset.tanimoto(&rhs_bitset), and / (total - and));
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn common_prefix_len(lhs: u32, rhs: u32) {
+            let lhs_bitset: SizedBitset<32> = lhs.into();
+            let rhs_bitset: SizedBitset<32> = rhs.into();
+            prop_assert_eq!(lhs_bitset.common_prefix_len(&rhs_bitset), (lhs ^ rhs).leading_zeros() as usize);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn rank(bits: u8) {
+            let bitset: SizedBitset<8> = bits.into();
+            for i in 0..=8 {
+                prop_assert_eq!(bitset.rank(i), (bits as u16 & ((1 << i) - 1)).count_ones() as usize);
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn select(bits: u8) {
+            let bitset: SizedBitset<8> = bits.into();
+            for k in 0..8 {
+                let expected = (0..8).filter(|i| (bits >> i) & 1 == 1).nth(k);
+                prop_assert_eq!(bitset.select(k), expected);
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn rank_select(bits in proptest::collection::vec(proptest::bool::weighted(0.2), 300)) {
+            let bitset: SizedBitset<300> = bits.as_slice().try_into().unwrap();
+            let directory = RankSelect::new(bitset);
+            prop_assert_eq!(directory.count(), bitset.count());
+            for i in 0..=300 {
+                prop_assert_eq!(directory.rank(i), bitset.rank(i));
+            }
+            for k in 0..=bitset.count() {
+                prop_assert_eq!(directory.select(k), bitset.select(k));
+            }
+        }
+    }
+
+    fn naive_runs(bits: u16) -> Vec<(usize, usize, bool)> {
+        let mut runs: Vec<(usize, usize, bool)> = vec![];
+        for i in 0..16 {
+            let bit = (bits >> i) & 1 == 1;
+            match runs.last_mut() {
+                Some((_, len, value)) if *value == bit => *len += 1,
+                _ => runs.push((i, 1, bit)),
+            }
+        }
+        runs
+    }
+
+    proptest! {
+        #[test]
+        fn diff(old: u64, new: u64) {
+            let old_bitset: SizedBitset<64> = old.into();
+            let new_bitset: SizedBitset<64> = new.into();
+            let expected = (0..64)
+                .filter(|i| ((old ^ new) >> i) & 1 == 1)
+                .map(|i| (i, (old >> i) & 1 == 1, (new >> i) & 1 == 1))
+                .collect::<Vec<_>>();
+            prop_assert_eq!(old_bitset.diff(&new_bitset).collect::<Vec<_>>(), expected);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn edges(prev: u64, current: u64) {
+            let prev_bitset: SizedBitset<64> = prev.into();
+            let current_bitset: SizedBitset<64> = current.into();
+            prop_assert_eq!(current_bitset.rising_edges(&prev_bitset).to_u64(), current & !prev);
+            prop_assert_eq!(current_bitset.falling_edges(&prev_bitset).to_u64(), !current & prev);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn patch(base: u64, target: u64) {
+            let base_bitset: SizedBitset<64> = base.into();
+            let target_bitset: SizedBitset<64> = target.into();
+            let patch = base_bitset.patch_to(&target_bitset);
+            prop_assert_eq!(patch.to_u64(), base ^ target);
+            prop_assert_eq!(base_bitset.apply_patch(&patch), target_bitset);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn get_bits(bits: u128, start in 0usize..=128, width in 0usize..=128) {
+            let end = (start + width).min(128);
+            let bitset: SizedBitset<128> = bits.into();
+            let mask = u128::MAX.checked_shr(128 - (end - start) as u32).unwrap_or(0);
+            let expected = bits.checked_shr(start as u32).unwrap_or(0) & mask;
+            prop_assert_eq!(bitset.get_bits(start..end), expected);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn set_bits(bits: u128, start in 0usize..128, width in 1usize..=128, value: u128) {
+            let end = (start + width).min(128);
+            let mask = u128::MAX >> (128 - (end - start));
+            let mut bitset: SizedBitset<128> = bits.into();
+
+            let is_err = bitset.set_bits(start..end, value).is_err();
+            prop_assert_eq!(is_err, value & !mask != 0);
+            if is_err {
+                prop_assert_eq!(bitset.to_u128(), bits);
+            } else {
+                prop_assert_eq!(bitset.to_u128(), (bits & !(mask << start)) | (value << start));
+                prop_assert_eq!(bitset.get_bits(start..end), value);
+            }
+        }
+    }
+
+    #[test]
+    fn field_wider_than_128_bits() {
+        let mut bitset = SizedBitset::<256>::new();
+        bitset.set_bits(64..256, u128::MAX).unwrap();
+        assert_eq!(bitset.get_bits(..64), 0);
+        assert_eq!(bitset.get_bits(64..192), u128::MAX);
+        assert_eq!(bitset.get_bits(192..), 0);
+    }
+
+    proptest! {
+        #[test]
+        fn lanes(lhs: [u8; 8], rhs: [u8; 8]) {
+            // 8 lanes of 5 bits
+            let lhs = lhs.map(|lane| lane as u128 & 0b11111);
+            let rhs = rhs.map(|lane| lane as u128 & 0b11111);
+            let mut lhs_lanes = SizedBitset::<40>::new().lanes::<5>();
+            let mut rhs_lanes = SizedBitset::<40>::new().lanes::<5>();
+            for i in 0..8 {
+                lhs_lanes.set_lane(i, lhs[i]).unwrap();
+                rhs_lanes.set_lane(i, rhs[i]).unwrap();
+            }
+            prop_assert!(lhs_lanes.set_lane(0, 0b100000).is_err());
+
+            let sum = lhs_lanes.wrapping_add(&rhs_lanes);
+            let difference = lhs_lanes.wrapping_sub(&rhs_lanes);
+            let eq = lhs_lanes.eq_mask(&rhs_lanes).lanes::<5>();
+            let lt = lhs_lanes.lt_mask(&rhs_lanes).lanes::<5>();
+            let gt = lhs_lanes.gt_mask(&rhs_lanes).lanes::<5>();
+            for i in 0..8 {
+                prop_assert_eq!(lhs_lanes.lane(i), lhs[i]);
+                prop_assert_eq!(sum.lane(i), (lhs[i] + rhs[i]) & 0b11111);
+                prop_assert_eq!(difference.lane(i), lhs[i].wrapping_sub(rhs[i]) & 0b11111);
+                prop_assert_eq!(eq.lane(i), if lhs[i] == rhs[i] { 0b11111 } else { 0 });
+                prop_assert_eq!(lt.lane(i), if lhs[i] < rhs[i] { 0b11111 } else { 0 });
+                prop_assert_eq!(gt.lane(i), if lhs[i] > rhs[i] { 0b11111 } else { 0 });
+            }
+            prop_assert_eq!(lhs_lanes.sum(), lhs.iter().sum::<u128>());
+        }
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "the lane width must be in 1..=128 and divide the size 256, but it is 256"
+    )]
+    fn lanes_splat_too_wide() {
+        Lanes::<256, 256>::splat(1);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "the lane width must be in 1..=128 and divide the size 8, but it is 0"
+    )]
+    fn lanes_splat_zero_width() {
+        Lanes::<8, 0>::splat(1);
+    }
+
+    proptest! {
+        #[test]
+        fn runs(bits: u16) {
+            let bitset: SizedBitset<16> = bits.into();
+            prop_assert_eq!(bitset.runs().collect::<Vec<_>>(), naive_runs(bits));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn longest_run(bits: u16, value: bool) {
+            let bitset: SizedBitset<16> = bits.into();
+            let expected = naive_runs(bits)
+                .into_iter()
+                .filter(|(_, _, bit)| *bit == value)
+                .rev()
+                .max_by_key(|(_, len, _)| *len)
+                .map(|(start, len, _)| (start, len));
+            prop_assert_eq!(bitset.longest_run(value), expected);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn find_run(bits: u16, value: bool) {
+            let bitset: SizedBitset<16> = bits.into();
+            for k in 1..=17 {
+                let expected = (0..16)
+                    .find(|&i| i + k <= 16 && (i..i + k).all(|j| ((bits >> j) & 1 == 1) == value));
+                prop_assert_eq!(bitset.find_run(value, k), expected);
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn find_run_best_fit(bits: u16, value: bool) {
+            let bitset: SizedBitset<16> = bits.into();
+            for k in 1..=17 {
+                let expected = naive_runs(bits)
+                    .into_iter()
+                    .filter(|(_, len, bit)| *bit == value && *len >= k)
+                    .min_by_key(|(_, len, _)| *len)
+                    .map(|(start, _, _)| start);
+                prop_assert_eq!(bitset.find_run_best_fit(value, k), expected);
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn map(bits: u8, mask: u8) {
+            let bitset: SizedBitset<8> = bits.into();
+            let mapped = bitset.map(|i, bit| bit ^ ((mask >> i) & 1 == 1));
+            prop_assert_eq!(mapped.to_u8(), bits ^ mask);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn zip_with(lhs: u8, rhs: u8) {
+            let lhs_bitset: SizedBitset<8> = lhs.into();
+            let rhs_bitset: SizedBitset<8> = rhs.into();
+            prop_assert_eq!(lhs_bitset.zip_with(&rhs_bitset, |a, b| !a || b).to_u8(), !lhs | rhs);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn fold(bits: u8) {
+            let bitset: SizedBitset<8> = bits.into();
+            let folded = bitset.fold(0u8, |acc, i, bit| acc | ((bit as u8) << i));
+            prop_assert_eq!(folded, bits);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn try_for_each_one(bits: u8, limit in 0usize..=8) {
+            let bitset: SizedBitset<8> = bits.into();
+            let mut visited = vec![];
+            let flow = bitset.try_for_each_one(|i| {
+                if i >= limit {
+                    return ControlFlow::Break(i);
+                }
+                visited.push(i);
+                ControlFlow::Continue(())
+            });
+            let ones = (0..8).filter(|i| (bits >> i) & 1 == 1).collect::<Vec<_>>();
+            let expected = match ones.iter().find(|i| **i >= limit) {
+                Some(i) => ControlFlow::Break(*i),
+                None => ControlFlow::Continue(()),
+            };
+            prop_assert_eq!(flow, expected);
+            prop_assert_eq!(visited, ones.into_iter().filter(|i| *i < limit).collect::<Vec<_>>());
+        }
+    }
+
     proptest! {
         #[test]
         fn flip(mut bitset: SizedBitset<4>) {
@@ -200,6 +581,837 @@ mod test {
         }
     }
 
+    proptest! {
+        #[test]
+        fn checked_shl(bits: u8) {
+            let bitset: SizedBitset<8> = bits.into();
+            for i in 0..=16 {
+                let wide = (bits as u32) << i;
+                let expected = (wide <= u8::MAX as u32).then_some(wide as u8);
+                prop_assert_eq!(bitset.checked_shl(i).map(|b| b.to_u8()), expected);
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn checked_shr(bits: u8) {
+            let bitset: SizedBitset<8> = bits.into();
+            for i in 0..=16 {
+                let wide = ((bits as u32) << 16) >> i;
+                let expected = (wide & 0xffff == 0).then_some((wide >> 16) as u8);
+                prop_assert_eq!(bitset.checked_shr(i).map(|b| b.to_u8()), expected);
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn overflowing_shl(bits: u8) {
+            let bitset: SizedBitset<8> = bits.into();
+            for i in 0..=16 {
+                let wide = (bits as u32) << i;
+                let (shifted, overflow) = bitset.overflowing_shl(i);
+                prop_assert_eq!(shifted.to_u8(), wide as u8);
+                prop_assert_eq!(overflow.to_u8(), (wide >> 8) as u8);
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn overflowing_shr(bits: u8) {
+            let bitset: SizedBitset<8> = bits.into();
+            for i in 0..=16 {
+                let wide = ((bits as u32) << 8) >> i;
+                let (shifted, overflow) = bitset.overflowing_shr(i);
+                prop_assert_eq!(shifted.to_u8(), (wide >> 8) as u8);
+                prop_assert_eq!(overflow.to_u8(), wide as u8);
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn shl_in(bits: u8, bit: bool) {
+            let mut bitset: SizedBitset<8> = bits.into();
+            prop_assert_eq!(bitset.shl_in(bit), bits >> 7 == 1);
+            prop_assert_eq!(bitset.to_u8(), (bits << 1) | bit as u8);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn shr_in(bits: u8, bit: bool) {
+            let mut bitset: SizedBitset<8> = bits.into();
+            prop_assert_eq!(bitset.shr_in(bit), bits & 1 == 1);
+            prop_assert_eq!(bitset.to_u8(), (bits >> 1) | ((bit as u8) << 7));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn funnel_shl(hi: u8, lo: u8) {
+            let concat = ((hi as u32) << 8) | lo as u32;
+            for i in 0..=16 {
+                let funnel = SizedBitset::<8>::funnel_shl(hi.into(), lo.into(), i);
+                prop_assert_eq!(funnel.to_u8(), ((concat << i) >> 8) as u8);
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn funnel_shr(hi: u8, lo: u8) {
+            let concat = ((hi as u32) << 8) | lo as u32;
+            for i in 0..=16 {
+                let funnel = SizedBitset::<8>::funnel_shr(hi.into(), lo.into(), i);
+                prop_assert_eq!(funnel.to_u8(), (concat >> i) as u8);
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn reverse_bits(bits: u32) {
+            let bitset: SizedBitset<32> = bits.into();
+            prop_assert_eq!(bitset.reverse_bits().to_u32(), bits.reverse_bits());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn swap_bytes(bits: u64) {
+            let bitset: SizedBitset<64> = bits.into();
+            prop_assert_eq!(bitset.swap_bytes().to_u64(), bits.swap_bytes());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn reverse_within(bits: u32) {
+            let bitset: SizedBitset<32> = bits.into();
+            prop_assert_eq!(bitset.reverse_within::<1>().to_u32(), bits);
+            prop_assert_eq!(bitset.reverse_within::<8>().to_u32(), bits.swap_bytes().reverse_bits());
+            prop_assert_eq!(bitset.reverse_within::<16>().to_u32(), bits.reverse_bits().rotate_left(16));
+            prop_assert_eq!(bitset.reverse_within::<32>().to_u32(), bits.reverse_bits());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn to_gray(bits: u16) {
+            let bitset: SizedBitset<16> = bits.into();
+            prop_assert_eq!(bitset.to_gray().to_u16(), bits ^ (bits >> 1));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn from_gray(bitset: SizedBitset<16>) {
+            prop_assert_eq!(SizedBitset::from_gray(bitset.to_gray()), bitset);
+            prop_assert_eq!(SizedBitset::from_gray(bitset).to_gray(), bitset);
+        }
+    }
+
+    #[test]
+    fn gray_codes() {
+        let codes = SizedBitset::<8>::gray_codes().collect::<Vec<_>>();
+        assert_eq!(codes.len(), 256);
+        for (i, code) in codes.iter().enumerate() {
+            assert_eq!(SizedBitset::from_gray(*code).to_u8(), i as u8);
+        }
+        for pair in codes.windows(2) {
+            assert_eq!((pair[0] ^ pair[1]).count(), 1);
+        }
+        assert_eq!(SizedBitset::<0>::gray_codes().count(), 1);
+    }
+
+    fn naive_deposit(source: &[bool], mask: &[bool]) -> Vec<bool> {
+        let mut source = source.iter();
+        mask.iter()
+            .map(|selected| *selected && *source.next().unwrap())
+            .collect()
+    }
+
+    fn naive_extract(source: &[bool], mask: &[bool]) -> Vec<bool> {
+        let mut extracted = source
+            .iter()
+            .zip(mask)
+            .filter(|(_, selected)| **selected)
+            .map(|(bit, _)| *bit)
+            .collect::<Vec<_>>();
+        extracted.resize(source.len(), false);
+        extracted
+    }
+
+    proptest! {
+        #[test]
+        fn deposit(
+            source in proptest::collection::vec(proptest::bool::ANY, 150),
+            mask in proptest::collection::vec(proptest::bool::ANY, 150),
+        ) {
+            let bitset: SizedBitset<150> = source.as_slice().try_into().unwrap();
+            let mask_bitset: SizedBitset<150> = mask.as_slice().try_into().unwrap();
+            let expected: SizedBitset<150> = naive_deposit(&source, &mask).as_slice().try_into().unwrap();
+            prop_assert_eq!(bitset.deposit(&mask_bitset), expected);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn extract(
+            source in proptest::collection::vec(proptest::bool::ANY, 150),
+            mask in proptest::collection::vec(proptest::bool::ANY, 150),
+        ) {
+            let bitset: SizedBitset<150> = source.as_slice().try_into().unwrap();
+            let mask_bitset: SizedBitset<150> = mask.as_slice().try_into().unwrap();
+            let expected: SizedBitset<150> = naive_extract(&source, &mask).as_slice().try_into().unwrap();
+            prop_assert_eq!(bitset.extract(&mask_bitset), expected);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn permute(bits: u8, table: [usize; 12]) {
+            let table = table.map(|index| index % 8);
+            let bitset: SizedBitset<8> = bits.into();
+            let permuted = bitset.permute(&table);
+            for j in 0..12 {
+                prop_assert_eq!(permuted[j], (bits >> table[j]) & 1 == 1);
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn bit_permutation(bitset: SizedBitset<8>, table in Just((0..8).collect::<Vec<usize>>()).prop_shuffle()) {
+            let table: [usize; 8] = table.try_into().unwrap();
+            let permutation = BitPermutation::<8, 8>::new(table).unwrap();
+            let inverse = permutation.inverse().unwrap();
+            prop_assert_eq!(inverse.table(), &inverse_permutation(&table).unwrap());
+            prop_assert_eq!(permutation.apply(&bitset), bitset.permute(&table));
+            prop_assert_eq!(inverse.apply(&permutation.apply(&bitset)), bitset);
+        }
+    }
+
+    #[test]
+    fn bit_permutation_error() {
+        assert!(matches!(
+            BitPermutation::<4, 2>::new([1, 4]),
+            Err(PermutationError::OutOfRange {
+                position: 1,
+                index: 4,
+                size: 4
+            })
+        ));
+        assert!(matches!(
+            BitPermutation::<4, 4>::new([0, 1, 1, 2]).unwrap().inverse(),
+            Err(PermutationError::Duplicated { index: 1 })
+        ));
+        assert!(matches!(
+            BitPermutation::<4, 2>::new([0, 1]).unwrap().inverse(),
+            Err(PermutationError::SizeMismatch { from: 4, to: 2 })
+        ));
+    }
+
+    proptest! {
+        #[test]
+        fn interleave(x: u32, y: u32) {
+            let key = SizedBitset::<32>::interleave(x.into(), y.into());
+            let expected = (0..32).fold(0u64, |key, i| {
+                key | ((((x >> i) & 1) as u64) << (2 * i)) | ((((y >> i) & 1) as u64) << (2 * i + 1))
+            });
+            prop_assert_eq!(key.to_u64(), expected);
+            prop_assert_eq!(key.deinterleave(), (x.into(), y.into()));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn interleave3(x: u32, y: u32, z: u32) {
+            let (x, y, z) = (x >> 11, y >> 11, z >> 11);
+            let coordinates = [x, y, z].map(|c| SizedBitset::<21>::try_from_iter((0..21).map(|i| (c >> i) & 1 == 1)).unwrap());
+            let key = SizedBitset::<21>::interleave3(coordinates[0], coordinates[1], coordinates[2]);
+            let expected = (0..21).fold(0u64, |key, i| {
+                key | ((((x >> i) & 1) as u64) << (3 * i))
+                    | ((((y >> i) & 1) as u64) << (3 * i + 1))
+                    | ((((z >> i) & 1) as u64) << (3 * i + 2))
+            });
+            prop_assert_eq!(key.to_u64(), expected);
+            prop_assert_eq!(key.deinterleave3(), (coordinates[0], coordinates[1], coordinates[2]));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn transpose(rows: [u8; 8]) {
+            let transposed = SizedBitset::transpose(rows.map(SizedBitset::<8>::from));
+            for (j, column) in transposed.iter().enumerate() {
+                for (i, row) in rows.iter().enumerate() {
+                    prop_assert_eq!(column[i], (row >> j) & 1 == 1);
+                }
+            }
+            prop_assert_eq!(SizedBitset::transpose(transposed), rows.map(SizedBitset::<8>::from));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn transpose_non_power_of_two(rows: [SizedBitset<5>; 5]) {
+            let transposed = SizedBitset::transpose(rows);
+            for (j, column) in transposed.iter().enumerate() {
+                for (i, row) in rows.iter().enumerate() {
+                    prop_assert_eq!(column[i], row[j]);
+                }
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn bit_planes(samples: [u8; 16]) {
+            let planes = SizedBitset::<16>::to_bit_planes(&samples);
+            for (b, plane) in planes.iter().enumerate() {
+                for (i, sample) in samples.iter().enumerate() {
+                    prop_assert_eq!(plane[i], (sample >> b) & 1 == 1);
+                }
+            }
+            prop_assert_eq!(SizedBitset::from_bit_planes(&planes), samples);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn ternary_logic(a: u16, b: u16, c: u16, imm: u8) {
+            let expected = (0..16).fold(0u16, |result, i| {
+                let index = ((a >> i) & 1) << 2 | ((b >> i) & 1) << 1 | ((c >> i) & 1);
+                result | ((((imm >> index) & 1) as u16) << i)
+            });
+            let ternary = SizedBitset::<16>::ternary_logic(a.into(), b.into(), c.into(), imm);
+            prop_assert_eq!(ternary.to_u16(), expected);
+            prop_assert_eq!(SizedBitset::eval_truth_table(&[c.into(), b.into(), a.into()], SizedBitset::<8>::from(imm)), ternary);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn mux(mask: u16, a: u16, b: u16) {
+            let mux = SizedBitset::<16>::mux(mask.into(), a.into(), b.into());
+            prop_assert_eq!(mux.to_u16(), (a & mask) | (b & !mask));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn eval_truth_table(inputs: [u8; 4], table: u16) {
+            let bitsets = inputs.map(SizedBitset::<8>::from);
+            let result = SizedBitset::eval_truth_table(&bitsets, SizedBitset::<16>::from(table));
+            for i in 0..8 {
+                let index = (0..4).fold(0, |index, k| index | ((inputs[k] >> i) & 1) << k);
+                prop_assert_eq!(result[i], (table >> index) & 1 == 1);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "the truth table of 128 inputs must have 2^128 entries, but it has 2"
+    )]
+    fn eval_truth_table_too_many_inputs() {
+        SizedBitset::<1>::eval_truth_table(&[SizedBitset::new(); 128], SizedBitset::<2>::new());
+    }
+
+    fn naive_clmul(lhs: u64, rhs: u64) -> u128 {
+        (0..64)
+            .filter(|i| (rhs >> i) & 1 == 1)
+            .fold(0, |product, i| product ^ ((lhs as u128) << i))
+    }
+
+    proptest! {
+        #[test]
+        fn degree(bits: u64) {
+            let poly: SizedBitset<64> = bits.into();
+            prop_assert_eq!(poly.degree(), (bits != 0).then(|| 63 - bits.leading_zeros() as usize));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn clmul(lhs: u64, rhs: u64) {
+            let lhs_poly: SizedBitset<64> = lhs.into();
+            let rhs_poly: SizedBitset<64> = rhs.into();
+            prop_assert_eq!(lhs_poly.clmul::<128>(&rhs_poly).to_u128(), naive_clmul(lhs, rhs));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn clmul_multi_word(
+            lhs in proptest::collection::vec(proptest::bool::ANY, 100),
+            rhs in proptest::collection::vec(proptest::bool::ANY, 100),
+        ) {
+            let lhs_poly: SizedBitset<100> = lhs.as_slice().try_into().unwrap();
+            let rhs_poly: SizedBitset<100> = rhs.as_slice().try_into().unwrap();
+            let mut expected = vec![false; 199];
+            for i in (0..100).filter(|&i| lhs[i]) {
+                for j in (0..100).filter(|&j| rhs[j]) {
+                    expected[i + j] ^= true;
+                }
+            }
+            let expected: SizedBitset<199> = expected.as_slice().try_into().unwrap();
+            prop_assert_eq!(lhs_poly.clmul::<199>(&rhs_poly), expected);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn poly_div_rem(dividend: u32, divisor in 1u32..) {
+            let dividend_poly: SizedBitset<32> = dividend.into();
+            let divisor_poly: SizedBitset<32> = divisor.into();
+            let (quotient, remainder) = dividend_poly.poly_div_rem(&divisor_poly);
+            prop_assert!(remainder.degree() < divisor_poly.degree());
+            prop_assert_eq!(
+                naive_clmul(quotient.to_u32() as u64, divisor as u64) as u32 ^ remainder.to_u32(),
+                dividend
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn poly_mod(poly: u64, modulus in 1u16..) {
+            let poly_bitset: SizedBitset<64> = poly.into();
+            let modulus_bitset: SizedBitset<16> = modulus.into();
+            let (_, expected) = poly_bitset.poly_div_rem(&(modulus as u64).into());
+            prop_assert_eq!(poly_bitset.poly_mod(&modulus_bitset).to_u16() as u64, expected.to_u64());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn poly_gcd(lhs: u16, rhs: u16, common in 1u16..) {
+            let lhs_poly: SizedBitset<64> = (naive_clmul(lhs as u64, common as u64) as u64).into();
+            let rhs_poly: SizedBitset<64> = (naive_clmul(rhs as u64, common as u64) as u64).into();
+            let gcd = lhs_poly.poly_gcd(&rhs_poly);
+            if lhs_poly.any() || rhs_poly.any() {
+                prop_assert!(lhs_poly.poly_div_rem(&gcd).1.none());
+                prop_assert!(rhs_poly.poly_div_rem(&gcd).1.none());
+                prop_assert!(gcd.poly_div_rem(&common.into()).1.none());
+            } else {
+                prop_assert!(gcd.none());
+            }
+        }
+    }
+
+    #[test]
+    fn combinations() {
+        for k in 0..=9 {
+            let combinations = SizedBitset::<8>::combinations(k).collect::<Vec<_>>();
+            let expected = (0..=u8::MAX)
+                .filter(|bits| bits.count_ones() as usize == k)
+                .map(SizedBitset::<8>::from)
+                .collect::<Vec<_>>();
+            assert_eq!(combinations, expected);
+            for (rank, combination) in combinations.iter().enumerate() {
+                assert_eq!(combination.rank_combination().unwrap(), rank as u128);
+                assert_eq!(
+                    SizedBitset::unrank_combination(k, rank as u128).unwrap(),
+                    *combination
+                );
+            }
+            assert!(matches!(
+                SizedBitset::<8>::unrank_combination(k, combinations.len() as u128),
+                Err(CombinationError::OutOfRange { .. })
+            ));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn rank_combination(bits in proptest::collection::vec(proptest::bool::ANY, 80)) {
+            let bitset: SizedBitset<80> = bits.as_slice().try_into().unwrap();
+            let rank = bitset.rank_combination().unwrap();
+            prop_assert_eq!(SizedBitset::unrank_combination(bitset.count(), rank).unwrap(), bitset);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn rank_combination_wide(bits: u128) {
+            // every rank fits in u128 when N = 128
+            let bitset = SizedBitset::<128>::from(bits);
+            let rank = bitset.rank_combination().unwrap();
+            prop_assert_eq!(SizedBitset::unrank_combination(bitset.count(), rank).unwrap(), bitset);
+        }
+    }
+
+    #[test]
+    fn rank_combination_large_binomial() {
+        // C(126, 63) and C(127, 63)
+        let first = 0x48a493d789a7b189e9a741cbf235740;
+        let total = 0x9026955fb528c44daba7e690b4a2123;
+
+        let bitset = SizedBitset::<127>::mask_low(62) | SizedBitset::single(126);
+        assert_eq!(bitset.rank_combination().unwrap(), first);
+        assert_eq!(
+            SizedBitset::<127>::unrank_combination(63, first).unwrap(),
+            bitset
+        );
+
+        let last = SizedBitset::<127>::mask_high(63);
+        assert_eq!(last.rank_combination().unwrap(), total - 1);
+        assert_eq!(
+            SizedBitset::<127>::unrank_combination(63, total - 1).unwrap(),
+            last
+        );
+        assert!(matches!(
+            SizedBitset::<127>::unrank_combination(63, total),
+            Err(CombinationError::OutOfRange { .. })
+        ));
+    }
+
+    #[test]
+    fn rank_combination_overflow() {
+        let bitset = SizedBitset::<256>::mask_high(128);
+        assert!(matches!(
+            bitset.rank_combination(),
+            Err(CombinationError::Overflow)
+        ));
+        let first = SizedBitset::<256>::unrank_combination(128, 0).unwrap();
+        assert_eq!(first, SizedBitset::mask_low(128));
+    }
+
+    proptest! {
+        #[test]
+        fn submasks(mask: u16) {
+            let submasks = SizedBitset::<16>::from(mask)
+                .submasks()
+                .map(|bitset| bitset.to_u16())
+                .collect::<Vec<_>>();
+            let expected = (0..=mask).rev().filter(|s| s & mask == *s).collect::<Vec<_>>();
+            prop_assert_eq!(submasks, expected);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn proper_submasks(indices in proptest::collection::btree_set(0..100usize, 0..8)) {
+            let mask = SizedBitset::<100>::from_fn(|i| indices.contains(&i));
+            let submasks = mask.proper_submasks().collect::<Vec<_>>();
+            prop_assert_eq!(submasks.len(), (1 << indices.len()) - 1);
+            prop_assert!(submasks.iter().all(|s| *s & mask == *s && *s != mask));
+            prop_assert_eq!(submasks.iter().collect::<std::collections::HashSet<_>>().len(), submasks.len());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn supermasks_within(base: u16, universe: u16) {
+            let supermasks = SizedBitset::<16>::from(base)
+                .supermasks_within(&SizedBitset::from(universe))
+                .map(|bitset| bitset.to_u16())
+                .collect::<Vec<_>>();
+            let expected = (base..=(base | universe))
+                .filter(|s| s & base == base && s & !(base | universe) == 0)
+                .collect::<Vec<_>>();
+            prop_assert_eq!(supermasks, expected);
+        }
+    }
+
+    #[test]
+    fn all_values() {
+        let values = SizedBitset::<16>::all_values();
+        assert_eq!(values.len(), 1 << 16);
+        assert!(values.map(|bitset| bitset.to_u16()).eq(0..=u16::MAX));
+        assert!(SizedBitset::<16>::all_values()
+            .rev()
+            .map(|bitset| bitset.to_u16())
+            .eq((0..=u16::MAX).rev()));
+
+        let mut values = SizedBitset::<64>::all_values();
+        assert_eq!(values.next().unwrap().to_u64(), 0);
+        assert_eq!(values.next_back().unwrap().to_u64(), u64::MAX);
+        assert_eq!(values.len(), (1 << 64) - 2);
+        assert_eq!(values.size_hint(), (usize::MAX - 1, Some(usize::MAX - 1)));
+        assert_eq!(SizedBitset::<64>::all_values().len(), 1 << 64);
+        assert_eq!(
+            SizedBitset::<64>::all_values().size_hint(),
+            (usize::MAX, None)
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn values_in(lo in 0..(1u32 << 20), width in 0..4096u32, n: usize) {
+            let hi = (lo + width).min((1 << 20) - 1);
+            let range = SizedBitset::<20>::from_fn(|i| (lo >> i) & 1 == 1)
+                ..SizedBitset::<20>::from_fn(|i| (hi >> i) & 1 == 1);
+            let values = SizedBitset::values_in(range.clone());
+            prop_assert_eq!(values.len(), (lo..hi).len() as u128);
+            prop_assert_eq!(values.size_hint(), (lo..hi).size_hint());
+            prop_assert!(values.map(|bitset| bitset.to_u32()).eq(lo..hi));
+
+            let n = n % 8192;
+            prop_assert_eq!(
+                SizedBitset::values_in(range.clone()).nth(n).map(|bitset| bitset.to_u32()),
+                (lo..hi).nth(n)
+            );
+            prop_assert!(SizedBitset::values_in(range.start..=range.end)
+                .rev()
+                .map(|bitset| bitset.to_u32())
+                .eq((lo..=hi).rev()));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn subset_table_transforms(values in proptest::collection::vec(-1000i64..1000, 256)) {
+            let table: SubsetTable<8, i64> = values.clone().try_into().unwrap();
+
+            let mut subset = table.clone();
+            subset.subset_zeta();
+            let mut superset = table.clone();
+            superset.superset_zeta();
+            for mask in 0..256usize {
+                let key = SizedBitset::<8>::from(mask as u8);
+                let subset_sum = (0..256).filter(|s| s & mask == *s).map(|s| values[s]).sum::<i64>();
+                let superset_sum = (0..256).filter(|s| s & mask == mask).map(|s| values[s]).sum::<i64>();
+                prop_assert_eq!(subset[key], subset_sum);
+                prop_assert_eq!(superset[key], superset_sum);
+            }
+
+            subset.subset_mobius();
+            superset.superset_mobius();
+            prop_assert_eq!(&subset, &table);
+            prop_assert_eq!(&superset, &table);
+        }
+    }
+
+    #[test]
+    fn subset_table_conversion() {
+        let table = SubsetTable::<3, usize>::from_fn(|key| key.count());
+        assert_eq!(table.len(), 8);
+        assert_eq!(table.as_slice(), &[0, 1, 1, 2, 1, 2, 2, 3]);
+        assert!(table
+            .iter()
+            .zip(SizedBitset::<3>::all_values())
+            .all(|((key, value), expected)| key == expected && *value == key.count()));
+
+        assert!(matches!(
+            SubsetTable::<3, usize>::try_from(vec![0; 7]),
+            Err(ConversionError::LengthMismatch {
+                expected: 8,
+                actual: 7
+            })
+        ));
+        assert!(matches!(
+            SubsetTable::<3, usize>::try_from(vec![0; 9]),
+            Err(ConversionError::LengthMismatch {
+                expected: 8,
+                actual: 9
+            })
+        ));
+    }
+
+    proptest! {
+        #[test]
+        fn aggregate(values in proptest::collection::vec(proptest::num::u32::ANY, 0..40), k in 0..42usize) {
+            let bitsets = values.iter().map(|value| SizedBitset::<32>::from(*value)).collect::<Vec<_>>();
+            let counts = (0..32)
+                .map(|i| values.iter().filter(|value| (*value >> i) & 1 == 1).count())
+                .collect::<Vec<_>>();
+            let expected = |f: &dyn Fn(usize) -> bool| {
+                counts.iter().enumerate().filter(|(_, count)| f(**count)).map(|(i, _)| 1 << i).sum::<u32>()
+            };
+
+            prop_assert_eq!(SizedBitset::union_all(&bitsets).to_u32(), values.iter().fold(0, |x, y| x | y));
+            prop_assert_eq!(SizedBitset::intersection_all(&bitsets).to_u32(), values.iter().fold(u32::MAX, |x, y| x & y));
+            prop_assert_eq!(SizedBitset::majority(&bitsets).to_u32(), expected(&|count| 2 * count > values.len()));
+            prop_assert_eq!(SizedBitset::threshold(&bitsets, k).to_u32(), expected(&|count| count >= k));
+            prop_assert_eq!(
+                SizedBitset::column_counts(&bitsets).to_vec(),
+                counts.iter().map(|count| *count as u32).collect::<Vec<_>>()
+            );
+        }
+    }
+
+    macro_rules! unsigned_arithmetic {
+        ($name:ident, $primitive:ty, $size:literal, $to:ident) => {
+            proptest! {
+                #[test]
+                fn $name(lhs: $primitive, rhs: $primitive) {
+                    let x = SizedBitset::<$size>::from(lhs);
+                    let y = SizedBitset::<$size>::from(rhs);
+
+                    prop_assert_eq!((x + y).$to(), lhs.wrapping_add(rhs));
+                    prop_assert_eq!((x - y).$to(), lhs.wrapping_sub(rhs));
+                    prop_assert_eq!((x * y).$to(), lhs.wrapping_mul(rhs));
+                    prop_assert_eq!((-x).$to(), lhs.wrapping_neg());
+
+                    let (sum, carry) = x.overflowing_add(&y);
+                    prop_assert_eq!((sum.$to(), carry), lhs.overflowing_add(rhs));
+                    let (difference, borrow) = x.overflowing_sub(&y);
+                    prop_assert_eq!((difference.$to(), borrow), lhs.overflowing_sub(rhs));
+                    let (product, overflow) = x.overflowing_mul(&y);
+                    prop_assert_eq!((product.$to(), overflow), lhs.overflowing_mul(rhs));
+                    let (negation, overflow) = x.overflowing_neg();
+                    prop_assert_eq!((negation.$to(), overflow), lhs.overflowing_neg());
+
+                    prop_assert_eq!(x.checked_add(&y).map(|z| z.$to()), lhs.checked_add(rhs));
+                    prop_assert_eq!(x.checked_sub(&y).map(|z| z.$to()), lhs.checked_sub(rhs));
+                    prop_assert_eq!(x.checked_mul(&y).map(|z| z.$to()), lhs.checked_mul(rhs));
+                    prop_assert_eq!(x.checked_div(&y).map(|z| z.$to()), lhs.checked_div(rhs));
+                    prop_assert_eq!(x.checked_rem(&y).map(|z| z.$to()), lhs.checked_rem(rhs));
+                    prop_assert_eq!(x.checked_neg().map(|z| z.$to()), lhs.checked_neg());
+
+                    prop_assert_eq!(x.saturating_add(&y).$to(), lhs.saturating_add(rhs));
+                    prop_assert_eq!(x.saturating_sub(&y).$to(), lhs.saturating_sub(rhs));
+                    prop_assert_eq!(x.saturating_mul(&y).$to(), lhs.saturating_mul(rhs));
+
+                    if rhs != 0 {
+                        prop_assert_eq!((x / y).$to(), lhs / rhs);
+                        prop_assert_eq!((x % y).$to(), lhs % rhs);
+                    }
+                }
+            }
+        };
+    }
+
+    unsigned_arithmetic!(unsigned_arithmetic_u8, u8, 8, to_u8);
+    unsigned_arithmetic!(unsigned_arithmetic_u16, u16, 16, to_u16);
+    unsigned_arithmetic!(unsigned_arithmetic_u32, u32, 32, to_u32);
+    unsigned_arithmetic!(unsigned_arithmetic_u64, u64, 64, to_u64);
+    unsigned_arithmetic!(unsigned_arithmetic_u128, u128, 128, to_u128);
+
+    proptest! {
+        #[test]
+        fn unsigned_arithmetic_odd_width(lhs in 0..(1u16 << 12), rhs in 1..(1u16 << 12)) {
+            let mut x = SizedBitset::<12>::from_fn(|i| (lhs >> i) & 1 == 1);
+            let y = SizedBitset::<12>::from_fn(|i| (rhs >> i) & 1 == 1);
+            let mask = (1 << 12) - 1;
+
+            prop_assert_eq!(x.overflowing_add(&y), (SizedBitset::from_fn(|i| ((lhs + rhs) >> i) & 1 == 1), lhs + rhs > mask));
+            prop_assert_eq!((x * y).to_u16(), lhs.wrapping_mul(rhs) & mask);
+            prop_assert_eq!(x.saturating_mul(&y).to_u16(), (lhs as u32 * rhs as u32).min(mask as u32) as u16);
+
+            x += y;
+            prop_assert_eq!(x.to_u16(), (lhs + rhs) & mask);
+            x -= y;
+            prop_assert_eq!(x.to_u16(), lhs);
+            x *= y;
+            prop_assert_eq!(x.to_u16(), lhs.wrapping_mul(rhs) & mask);
+            x /= y;
+            prop_assert_eq!(x.to_u16(), (lhs.wrapping_mul(rhs) & mask) / rhs);
+            x %= y;
+            prop_assert_eq!(x.to_u16(), (lhs.wrapping_mul(rhs) & mask) / rhs % rhs);
+        }
+    }
+
+    #[test]
+    fn gf2n_aes() {
+        let field = Gf2n::<8>::AES;
+        let tables = field.tables().unwrap();
+        assert_eq!(field.mul(0x57u8.into(), 0x13u8.into()).to_u8(), 0xfe);
+        assert_eq!(field.inverse(0u8.into()), None);
+        for a in 0..=u8::MAX {
+            let a: SizedBitset<8> = a.into();
+            if a.any() {
+                assert_eq!(field.mul(a, field.inverse(a).unwrap()), field.one());
+                assert_eq!(tables.inverse(a), field.inverse(a));
+            }
+            assert_eq!(tables.pow(a, 254), field.pow(a, 254));
+            for b in 0..=u8::MAX {
+                let b: SizedBitset<8> = b.into();
+                assert_eq!(tables.mul(a, b), field.mul(a, b));
+            }
+        }
+    }
+
+    #[test]
+    fn gf2n_reducible() {
+        // x^4 + x^2 + 1 = (x^2 + x + 1)^2
+        let field = Gf2n::<4>::new(SizedBitset::from_const([true, false, true, false]));
+        assert!(field.tables().is_none());
+        // x^16 + x^8 + 1 = (x^8 + x^4 + 1)^2
+        let field = Gf2n::<16>::new(SizedBitset::from_fn(|i| i == 0 || i == 8));
+        assert!(field.tables().is_none());
+    }
+
+    #[test]
+    fn gf2n_is_irreducible() {
+        // there are 2, 30 and 99 irreducible polynomials of degree 1, 8 and 10
+        let count = (0..2)
+            .filter(|modulus| {
+                Gf2n::<1>::new(SizedBitset::from_fn(|_| *modulus == 1)).is_irreducible()
+            })
+            .count();
+        assert_eq!(count, 2);
+        let count = (0..=u8::MAX)
+            .filter(|modulus| Gf2n::<8>::new((*modulus).into()).is_irreducible())
+            .count();
+        assert_eq!(count, 30);
+        let count = (0..1u32 << 10)
+            .filter(|modulus| {
+                Gf2n::<10>::new(SizedBitset::from_fn(|i| (modulus >> i) & 1 == 1)).is_irreducible()
+            })
+            .count();
+        assert_eq!(count, 99);
+    }
+
+    proptest! {
+        #[test]
+        fn gf2n_ghash(a: u128, b: u128, exp: u8) {
+            let field = Gf2n::<128>::GHASH;
+            let (a, b): (SizedBitset<128>, SizedBitset<128>) = (a.into(), b.into());
+            let mut modulus = SizedBitset::<129>::new();
+            modulus.set_bits(..8, 0x87).unwrap();
+            modulus.set(128);
+
+            let product = field.mul(a, b);
+            prop_assert_eq!(product, a.clmul::<255>(&b).poly_mod(&modulus).get_bits(..128).into());
+            prop_assert_eq!(field.mul(field.pow(a, exp as u128), a), field.pow(a, exp as u128 + 1));
+        }
+    }
+
+    #[test]
+    fn gf2n_ghash_inverse() {
+        let field = Gf2n::<128>::GHASH;
+        let a: SizedBitset<128> = 0x66e94bd4ef8a2c3b884cfa59ca342b2e_u128.into();
+        assert_eq!(field.mul(a, field.inverse(a).unwrap()), field.one());
+    }
+
+    #[test]
+    fn gf2n_ghash_test_vectors() {
+        let field = Gf2n::<128>::GHASH;
+        let block = |bytes: u128| SizedBitset::<128>::from(bytes).reverse_bits();
+        let ghash = |h: SizedBitset<128>, blocks: &[u128]| {
+            blocks
+                .iter()
+                .fold(SizedBitset::new(), |y, x| field.mul(y ^ block(*x), h))
+                .reverse_bits()
+                .to_u128()
+        };
+
+        // the test case 2 by McGrew and Viega
+        let h = block(0x66e94bd4ef8a2c3b884cfa59ca342b2e);
+        let blocks = [0x0388dace60b6a392f328c2b971b2fe78, 0x80];
+        assert_eq!(ghash(h, &blocks[..1]), 0x5e2ec746917062882c85b0685353deb7);
+        assert_eq!(ghash(h, &blocks), 0xf38cbb1ad69223dcc3457ae5b6b0f885);
+
+        // the test case 3 by McGrew and Viega
+        let h = block(0xb83b533708bf535d0aa6e52980d53b78);
+        let blocks = [
+            0x42831ec2217774244b7221b784d0d49c,
+            0xe3aa212f2c02a4e035c17e2329aca12e,
+            0x21d514b25466931c7d8f6a5aac84aa05,
+            0x1ba30b396a0aac973d58e091473f5985,
+            0x00000000000000000000000000000200,
+        ];
+        assert_eq!(ghash(h, &blocks[..1]), 0x59ed3f2bb1a0aaa07c9f56c6a504647b);
+        assert_eq!(ghash(h, &blocks), 0x7f1b32b81b820d02614f8895ac1d4eac);
+    }
+
     proptest! {
         #[test]
         fn bitand(lhs: u8, rhs: u8) {